@@ -0,0 +1,37 @@
+use aoc_common::{parse, ParseError, Solution};
+use log::debug;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    /// Calories carried per elf, sorted from most to least, never empty
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+        let mut vec_calories_per_elf: Vec<i32> = Vec::new();
+        for elf in parse::blocks(input) {
+            let current_elf_calories: i32 = parse::numbers::<i32>(elf)?.iter().sum();
+            vec_calories_per_elf.push(current_elf_calories);
+            debug!("Calories for this elf: {}", current_elf_calories);
+        }
+
+        if vec_calories_per_elf.is_empty() {
+            return Err(ParseError::new("No elves found", input));
+        }
+
+        vec_calories_per_elf.sort_by(|a, b| b.cmp(a));
+        Ok(vec_calories_per_elf)
+    }
+
+    /// Part 1: find max value in list
+    fn part1(calories: &Vec<i32>) -> i32 {
+        calories[0]
+    }
+
+    /// Part 2: find top 3 of list
+    fn part2(calories: &Vec<i32>) -> i32 {
+        calories.iter().take(3).sum()
+    }
+}
@@ -0,0 +1 @@
+aoc_common::example_tests!(aoc_2022_day_1::Day1, 2022, 1, "input");
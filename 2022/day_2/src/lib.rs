@@ -0,0 +1,101 @@
+use aoc_common::{ParseError, Solution};
+
+fn get_hand_score_p1(rps: &str) -> i64 {
+    if rps == "X" {
+        1
+    } else if rps == "Y" {
+        2
+    } else { 3 }
+}
+
+fn get_result_score_p1(rps: &[String]) -> i64 {
+    if rps[0] == "A" {  // Rock
+        if rps[1] == "X" {  // Rock
+            3
+        } else if rps[1] == "Y" { // Paper
+            6
+        } else { 0 } // Scissors
+    } else if rps[0] == "B" { // Paper
+        if rps[1] == "X" { // Rock
+            0
+        } else if rps[1] == "Y" { // Paper
+            3
+        } else { 6 } // Scissors
+    } else {  // Scissors
+        if rps[1] == "X" { //Rock
+            6
+        } else if rps[1] == "Y" { // Paper
+            0
+        } else { 3 }  // Scissors
+    }
+}
+
+fn get_result_score_p2(rps: &str) -> i64 {
+    if rps == "X" { // lose
+        0
+    } else if rps == "Y" { // draw
+        3
+    } else { 6 } // win
+}
+
+fn get_hand_score_p2(rps: &[String]) -> i64 {
+    if rps[0] == "A" {  // Rock
+        if rps[1] == "X" {  // Lose - Scissor
+            3
+        } else if rps[1] == "Y" { // Draw - Rock
+            1
+        } else { 2 } // Win - Paper
+    } else if rps[0] == "B" { // Paper
+        if rps[1] == "X" { // Lose - Rock
+            1
+        } else if rps[1] == "Y" { // Draw - Paper
+            2
+        } else { 3 } // Win - Scissors
+    } else {  // Scissors
+        if rps[1] == "X" { // Lose - Paper
+            2
+        } else if rps[1] == "Y" { // Draw - Scissors
+            3
+        } else { 1 }  // Win - Rock
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    /// Opponent's hand and own column for every round
+    type Input = Vec<Vec<String>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Vec<String>>, ParseError> {
+        input
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(|l| match l.split_once(" ") {
+                Some((opponent @ ("A" | "B" | "C"), own @ ("X" | "Y" | "Z"))) => {
+                    Ok(vec![opponent.to_string(), own.to_string()])
+                }
+                _ => Err(ParseError::new("Expected a round like `A Y`", l)),
+            })
+            .collect()
+    }
+
+    fn part1(rounds: &Vec<Vec<String>>) -> i64 {
+        let mut score_part_one: i64 = 0;
+        for result_vec in rounds {
+            score_part_one += get_hand_score_p1(&result_vec[1]);
+            score_part_one += get_result_score_p1(result_vec);
+        }
+        score_part_one
+    }
+
+    fn part2(rounds: &Vec<Vec<String>>) -> i64 {
+        let mut score_part_two: i64 = 0;
+        for result_vec in rounds {
+            score_part_two += get_result_score_p2(&result_vec[1]);
+            score_part_two += get_hand_score_p2(result_vec);
+        }
+        score_part_two
+    }
+}
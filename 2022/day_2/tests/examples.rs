@@ -0,0 +1 @@
+aoc_common::example_tests!(aoc_2022_day_2::Day2, 2022, 2, "input");
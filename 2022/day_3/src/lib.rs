@@ -0,0 +1,60 @@
+use aoc_common::{ParseError, Solution};
+
+fn get_priority(c: char) -> u32 {
+    let val: u32 = c as u32;
+    if val > 96 {
+        val - 96
+    } else {
+        val - 38
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    /// Contents of every rucksack
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        input
+            .lines()
+            .map(|l| match l.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+                Some((i, c)) => Err(ParseError::new("Invalid item", &l[i..i + c.len_utf8()])),
+                None => Ok(String::from(l)),
+            })
+            .collect()
+    }
+
+    fn part1(rucksacks: &Vec<String>) -> u32 {
+        let mut sum_of_priorities: u32 = 0;
+        for l in rucksacks {
+            let len = l.chars().count();
+            let (str1, str2) = l.split_at(len / 2);
+
+            for char in str1.chars() {
+                if str2.contains(char) {
+                    sum_of_priorities += get_priority(char);
+                    break;
+                }
+            }
+        }
+        sum_of_priorities
+    }
+
+    fn part2(rucksacks: &Vec<String>) -> u32 {
+        let mut sum_of_priorities_p2: u32 = 0;
+        for result_vec in rucksacks.chunks(3) {
+            if result_vec.len() == 3 {
+                for char in result_vec[0].chars() {
+                    if (result_vec[1].contains(char)) & (result_vec[2].contains(char)) {
+                        sum_of_priorities_p2 += get_priority(char);
+                        break;
+                    }
+                }
+            }
+        }
+        sum_of_priorities_p2
+    }
+}
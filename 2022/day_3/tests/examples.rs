@@ -0,0 +1 @@
+aoc_common::example_tests!(aoc_2022_day_3::Day3, 2022, 3, "input");
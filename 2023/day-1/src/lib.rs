@@ -0,0 +1,94 @@
+use aoc_common::{ParseError, Solution};
+use log::{debug, warn};
+use regex::Regex;
+
+fn replace_substring_with_number(input: &str) -> String {
+    let mut input_modified = input.to_string();
+    let re = Regex::new(r"one|two|three|four|five|six|seven|eight|nine").expect("Invalid regex");
+
+    while let Some(captures) = re.find(&input_modified) {
+        let number_str = captures.as_str();
+        let start_index = captures.start();
+        let number = match_number(number_str).expect("Invalid number");
+        input_modified.replace_range(start_index..start_index+1, &number.to_string());
+    }
+    input_modified
+}
+
+fn match_number(input: &str) -> Option<u32> {
+    let numbers = vec!["zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+    for (index, number) in numbers.iter().enumerate() {
+        if input == *number {
+            return Some(index as u32);
+        }
+    }
+    None
+}
+
+/// First and last digit of `line`, or `None` when it has no digits.
+fn first_and_last_digit(line: &str) -> Option<(u32, u32)> {
+    let mut digits = line.chars().filter_map(|c| c.to_digit(10));
+    let first = digits.next()?;
+    Some((first, digits.next_back().unwrap_or(first)))
+}
+
+fn combine((first, last): (u32, u32)) -> u32 {
+    first * 10 + last
+}
+
+/// First and last digit of a line of the calibration document
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Calibration {
+    /// Counting only numerals, `None` when the line has none
+    numerals: Option<(u32, u32)>,
+    /// Counting digits spelled out as words too
+    spelled: (u32, u32),
+}
+
+fn parse_calibration(line: &str) -> Result<Calibration, ParseError> {
+    let line_modified = replace_substring_with_number(line);
+    let spelled = first_and_last_digit(&line_modified)
+        .ok_or_else(|| ParseError::new("No digit found", line))?;
+    debug!("Line: {}. \nModified line: {}. \nNumbers: {:?}", line, line_modified, spelled);
+
+    Ok(Calibration {
+        numerals: first_and_last_digit(line),
+        spelled,
+    })
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    /// Calibration document, one entry per line
+    type Input = Vec<Calibration>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Calibration>, ParseError> {
+        input.lines().map(parse_calibration).collect()
+    }
+
+    /// Part 1: only numerals count, lines without any are left out
+    fn part1(calibrations: &Vec<Calibration>) -> u32 {
+        calibrations
+            .iter()
+            .enumerate()
+            .filter_map(|(i, calibration)| {
+                if calibration.numerals.is_none() {
+                    warn!("Line {} has no numerals, leaving it out", i + 1);
+                }
+                calibration.numerals
+            })
+            .map(combine)
+            .sum()
+    }
+
+    fn part2(calibrations: &Vec<Calibration>) -> u32 {
+        calibrations
+            .iter()
+            .map(|calibration| combine(calibration.spelled))
+            .sum()
+    }
+}
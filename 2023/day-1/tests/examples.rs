@@ -0,0 +1 @@
+aoc_common::example_tests!(aoc_2023_day_1::Day1, 2023, 1, "input");
@@ -0,0 +1,79 @@
+use aoc_common::{parse, ParseError, Solution};
+use regex::Regex;
+
+#[derive(Default)]
+pub struct Draw {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+impl Draw {
+    fn new(draw: &str, re: &Regex) -> Result<Self, ParseError> {
+        let mut result = Draw::default();
+        for captures in re.captures_iter(draw) {
+            let count = parse::number(&captures[1])?;
+            match &captures[2] {
+                "red" => result.red = count,
+                "green" => result.green = count,
+                _ => result.blue = count,
+            }
+        }
+        Ok(result)
+    }
+}
+
+fn parse_games(input: &str) -> Result<Vec<Vec<Draw>>, ParseError> {
+    let re = Regex::new(r"(\d+) (red|green|blue)").expect("Invalid regex");
+    let mut games = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        // remove Game #x: from line
+        let prefix = format!("Game {}: ", i + 1);
+        let line = line
+            .strip_prefix(&prefix)
+            .ok_or_else(|| ParseError::new(format!("Expected `{}`", prefix.trim_end()), line))?;
+        let draws = line.split("; ").map(|draw| Draw::new(draw, &re)).collect::<Result<_, _>>()?;
+        games.push(draws);
+    }
+    Ok(games)
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    /// Draws per game, in game order
+    type Input = Vec<Vec<Draw>>;
+    type Answer1 = usize;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Vec<Draw>>, ParseError> {
+        parse_games(input)
+    }
+
+    /// Part 1: sum the numbers of games possible with 12 red, 13 green and 14 blue cubes
+    fn part1(games: &Vec<Vec<Draw>>) -> usize {
+        games
+            .iter()
+            .enumerate()
+            .filter(|(_, draws)| {
+                draws
+                    .iter()
+                    .all(|d| d.red <= 12 && d.green <= 13 && d.blue <= 14)
+            })
+            .map(|(i, _)| i + 1)
+            .sum()
+    }
+
+    /// Part 2: sum the powers of the minimal cube set for each game
+    fn part2(games: &Vec<Vec<Draw>>) -> u32 {
+        games
+            .iter()
+            .map(|draws| {
+                let red = draws.iter().map(|d| d.red).max().unwrap_or(0);
+                let green = draws.iter().map(|d| d.green).max().unwrap_or(0);
+                let blue = draws.iter().map(|d| d.blue).max().unwrap_or(0);
+                red * green * blue
+            })
+            .sum()
+    }
+}
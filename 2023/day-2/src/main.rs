@@ -1,13 +1,9 @@
-use regex::Regex;
+use aoc_common::{store, InputArgs, MainError, Solution};
+use aoc_2023_day_2::Day2;
 
-fn main() {
-    let input_file = std::fs::read_to_string("input/dummy").expect("Unable to read file");
-    let mut game_number = 1;
-    for line in input_file.lines() {
-        // remove Game #x: from line
-        let line = line.replace(&format!("Game {}: ", game_number), "");
-        let draws = line.split("; ").collect::<Vec<&str>>();
-        game_number += 1;
-
-    }
+fn main() -> Result<(), MainError> {
+    let games = InputArgs::from_env().load::<Day2>(store::real_input(2023, 2), "input/dummy")?;
+    println!("Sum of possible game numbers: {}", Day2::part1(&games));
+    println!("Sum of minimal set powers: {}", Day2::part2(&games));
+    Ok(())
 }
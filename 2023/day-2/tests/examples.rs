@@ -0,0 +1 @@
+aoc_common::example_tests!(aoc_2023_day_2::Day2, 2023, 2, "input");
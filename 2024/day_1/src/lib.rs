@@ -0,0 +1,47 @@
+use aoc_common::{parse, ParseError, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    /// Left and right location lists, both sorted
+    type Input = (Vec<i32>, Vec<i32>);
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut left_list: Vec<i32> = Vec::new();
+        let mut right_list: Vec<i32> = Vec::new();
+
+        for line in input.lines().filter(|line| !line.trim().is_empty()) {
+            let numbers: Vec<i32> = parse::numbers(line)?;
+
+            if numbers.len() != 2 {
+                return Err(ParseError::new("Expected two location IDs", line));
+            }
+            left_list.push(numbers[0]);
+            right_list.push(numbers[1]);
+        }
+
+        left_list.sort();
+        right_list.sort();
+
+        Ok((left_list, right_list))
+    }
+
+    fn part1((left_list, right_list): &Self::Input) -> i32 {
+        left_list
+            .iter()
+            .zip(right_list.iter())
+            .map(|(left, right)| (left - right).abs())
+            .sum()
+    }
+
+    fn part2((left_list, right_list): &Self::Input) -> i32 {
+        left_list
+            .iter()
+            .map(|&left_value| {
+                left_value * right_list.iter().filter(|&x| x == &left_value).count() as i32
+            })
+            .sum()
+    }
+}
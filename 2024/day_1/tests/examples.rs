@@ -0,0 +1 @@
+aoc_common::example_tests!(aoc_2024_day_1::Day1, 2024, 1, "data");
@@ -0,0 +1,35 @@
+//! Random topographic maps for property tests.
+
+use aoc_common::random::Rng;
+use aoc_common::{Grid, Pos};
+
+/// A square height map with sides of `size + 1`, with `size` hiking trails from 0 to 9
+/// walked into otherwise random heights, so trails cross and share peaks.
+pub fn height_map(rng: &mut Rng, size: usize) -> String {
+    let side = size + 1;
+    let mut heights = Grid::new(side, side, 0);
+    for height in heights.values_mut() {
+        *height = rng.between(0, 9);
+    }
+
+    for _ in 0..size {
+        let mut trail = vec![Pos::new(rng.below(side), rng.below(side))];
+        while trail.len() < 10 {
+            let &last = trail.last().unwrap();
+            // never step back onto the trail, which would overwrite its lower part
+            let next: Vec<Pos> = heights
+                .neighbours4(last)
+                .filter(|pos| !trail.contains(pos))
+                .collect();
+            if next.is_empty() {
+                break;
+            }
+            trail.push(*rng.choose(&next));
+        }
+        for (height, &pos) in trail.iter().enumerate() {
+            heights[pos] = height;
+        }
+    }
+
+    heights.render(|_, &height| char::from_digit(height as u32, 10).unwrap())
+}
@@ -0,0 +1,194 @@
+pub mod generate;
+
+use aoc_common::search::{count_paths, Dfs};
+use aoc_common::{parse, Frame, Grid, Image, Palette, ParseError, Pos, Rgb, Simulation, Solution};
+use log::{debug, info};
+
+#[derive(Clone)]
+pub struct TopographicMap {
+    heights: Grid<usize>,
+    trailheads: Vec<Pos>,
+}
+
+impl TopographicMap {
+    fn new(height_map: &str) -> Result<Self, ParseError> {
+        let heights = parse::grid(height_map, |char| {
+            char.to_digit(10).map(|height| height as usize)
+        })?;
+        let trailheads = heights
+            .iter()
+            .filter(|(_, &height)| height == 0)
+            .map(|(pos, _)| pos)
+            .collect();
+
+        Ok(TopographicMap {
+            heights,
+            trailheads,
+        })
+    }
+
+    /// Neighbours of `pos` a trail can continue to, which are exactly one higher.
+    fn uphill(heights: &Grid<usize>, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        let next_height = heights[pos] + 1;
+        heights
+            .neighbours4(pos)
+            .filter(move |&next| heights[next] == next_height)
+    }
+
+    fn is_peak(&self, pos: Pos) -> bool {
+        self.heights[pos] == 9
+    }
+
+    /// Peaks reachable from the trailhead at `start`.
+    fn peaks(&self, start: Pos) -> Vec<Pos> {
+        Dfs::new(start, |&pos| Self::uphill(&self.heights, pos))
+            .filter(|&pos| self.is_peak(pos))
+            .collect()
+    }
+
+    /// Number of distinct trails from the trailhead at `start` to any peak.
+    fn rating(&self, start: Pos) -> usize {
+        count_paths(
+            start,
+            |&pos| Self::uphill(&self.heights, pos),
+            |&pos| self.is_peak(pos),
+        )
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = TopographicMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<TopographicMap, ParseError> {
+        let map = TopographicMap::new(input)?;
+        debug!("Trailheads: {:?}", map.trailheads);
+        Ok(map)
+    }
+
+    /// Part 1: calculating trailhead score
+    fn part1(map: &TopographicMap) -> usize {
+        let mut total_score = 0;
+        for &trailhead in &map.trailheads {
+            let peaks = map.peaks(trailhead);
+            debug!("Trailhead at {} has a score of {}", trailhead, peaks.len());
+            debug!("Reachable peaks: {:?}", peaks);
+            total_score += peaks.len();
+        }
+        info!("Total score: {}", total_score);
+        total_score
+    }
+
+    /// Part 2: calculating trailhead rating
+    fn part2(map: &TopographicMap) -> usize {
+        let mut total_rating = 0;
+        for &trailhead in &map.trailheads {
+            let rating = map.rating(trailhead);
+            debug!("Trailhead at {} has a rating of {}", trailhead, rating);
+            total_rating += rating;
+        }
+        info!("Total rating: {}", total_rating);
+        total_rating
+    }
+
+    /// The trails of part 1, coloured by the trailhead they start from and shaded by height
+    fn image(map: &TopographicMap) -> Option<Image> {
+        let palette = Palette::categorical(map.trailheads.len());
+
+        // the first trailhead to reach a cell gets to colour it
+        let mut trails: Grid<Option<usize>> = map.heights.map(|_| None);
+        for (i, &start) in map.trailheads.iter().enumerate() {
+            for pos in Dfs::new(start, |&pos| TopographicMap::uphill(&map.heights, pos)) {
+                trails[pos].get_or_insert(i);
+            }
+        }
+
+        Some(map.heights.to_image(|pos, &height| {
+            let brightness = (height + 1) as f64 / 10.0;
+            match trails[pos] {
+                Some(i) => Rgb::BLACK.mix(palette.colour(i), 0.3 + 0.7 * brightness),
+                None => Rgb::BLACK.mix(Rgb::GREY, 0.5 * brightness),
+            }
+        }))
+    }
+
+    fn simulation(map: &TopographicMap) -> Option<Box<dyn Simulation>> {
+        Some(Box::new(TrailSimulation {
+            visited: map.heights.map(|_| false),
+            map: map.clone(),
+            next_trailhead: 0,
+            search: None,
+            last_visited: None,
+            score: 0,
+            total_score: 0,
+        }))
+    }
+}
+
+type TrailSearch = Dfs<Pos, Box<dyn FnMut(&Pos) -> Vec<Pos>>>;
+
+/// The trail search of part 1, visiting one cell per step, trailhead after trailhead.
+struct TrailSimulation {
+    map: TopographicMap,
+    next_trailhead: usize,
+    search: Option<TrailSearch>,
+    /// Cells visited from the trailhead being explored
+    visited: Grid<bool>,
+    last_visited: Option<Pos>,
+    /// Score of the trailhead being explored
+    score: usize,
+    total_score: usize,
+}
+
+impl Simulation for TrailSimulation {
+    fn step(&mut self) -> bool {
+        loop {
+            if let Some(pos) = self.search.as_mut().and_then(|search| search.next()) {
+                self.visited[pos] = true;
+                self.last_visited = Some(pos);
+                if self.map.is_peak(pos) {
+                    self.score += 1;
+                    self.total_score += 1;
+                }
+                return true;
+            }
+
+            // this trailhead is done, start on the next one
+            let Some(&start) = self.map.trailheads.get(self.next_trailhead) else {
+                return false;
+            };
+            let heights = self.map.heights.clone();
+            self.search = Some(Dfs::new(
+                start,
+                Box::new(move |&pos| TopographicMap::uphill(&heights, pos).collect()),
+            ));
+            self.visited = self.map.heights.map(|_| false);
+            self.next_trailhead += 1;
+            self.score = 0;
+        }
+    }
+
+    fn frame(&self) -> Frame {
+        let text = self.map.heights.render(|pos, &height| {
+            if self.visited[pos] {
+                char::from_digit(height as u32, 10).unwrap()
+            } else {
+                '.'
+            }
+        });
+        Frame {
+            text,
+            focus: self.last_visited,
+            status: format!(
+                "trailhead {}/{}, score {}, total score {}",
+                self.next_trailhead,
+                self.map.trailheads.len(),
+                self.score,
+                self.total_score
+            ),
+        }
+    }
+}
@@ -0,0 +1 @@
+aoc_common::example_tests!(aoc_2024_day_10::Day10, 2024, 10, "data");
@@ -0,0 +1,47 @@
+//! The solver against exhaustive trail walking on random height maps.
+
+use std::collections::HashSet;
+
+use aoc_2024_day_10::{generate, Day10};
+use aoc_common::testing::check_reference;
+use aoc_common::{Grid, Pos};
+
+/// Every complete trail from `pos`, each ending on the peak it reaches.
+fn trail_ends(heights: &Grid<u32>, pos: Pos) -> Vec<Pos> {
+    if heights[pos] == 9 {
+        return vec![pos];
+    }
+    heights
+        .neighbours4(pos)
+        .filter(|&next| heights[next] == heights[pos] + 1)
+        .flat_map(|next| trail_ends(heights, next))
+        .collect()
+}
+
+/// Peaks reachable from each trailhead, and the number of trails to them.
+fn score_and_rating(input: &str) -> (usize, usize) {
+    let heights = Grid::parse(input, |c| c.to_digit(10).unwrap());
+    let (mut score, mut rating) = (0, 0);
+    for (pos, &height) in heights.iter() {
+        if height == 0 {
+            let ends = trail_ends(&heights, pos);
+            score += ends.iter().collect::<HashSet<_>>().len();
+            rating += ends.len();
+        }
+    }
+    (score, rating)
+}
+
+#[test]
+fn part1_matches_reference() {
+    check_reference::<Day10, _>("2024 day 10", 1, "score", generate::height_map, |input| {
+        score_and_rating(input).0
+    });
+}
+
+#[test]
+fn part2_matches_reference() {
+    check_reference::<Day10, _>("2024 day 10", 2, "rating", generate::height_map, |input| {
+        score_and_rating(input).1
+    });
+}
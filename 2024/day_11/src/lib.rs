@@ -0,0 +1,99 @@
+pub mod reference;
+
+use std::collections::HashMap;
+
+use aoc_common::digits::Digits;
+use aoc_common::oracle::Report;
+use aoc_common::{parse, ParseError, Solution};
+use log::{debug, info, trace};
+
+fn count_stones(stones: &[usize], blinks: usize) -> usize {
+    // index, (count, next)
+    let mut result_map: HashMap<usize, Vec<usize>> = HashMap::new();
+    let mut stones_map: HashMap<usize, usize> = HashMap::new();
+
+    // initialize stones
+    for s in stones.iter() {
+        let count = stones_map.entry(*s).or_insert(0);
+        *count += 1;
+    }
+
+    // initialize trivial results
+    result_map.insert(0, vec![1]);
+    result_map.insert(1, vec![2024]);
+
+    for i in 0..blinks {
+        stones_map = blink(&mut stones_map, &mut result_map);
+        let num_stones = stones_map.values().sum::<usize>();
+        info!("# {}.    {} stones", i + 1, num_stones);
+    }
+    debug!("Hashmap size: {}", result_map.len());
+    trace!(
+        "Result hashmap: {:?}",
+        result_map.iter().collect::<Vec<_>>()
+    );
+
+    stones_map.values().sum()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    /// Engravings on the stones, in order
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        parse::numbers(input)
+    }
+
+    fn part1(stones: &Vec<usize>) -> usize {
+        count_stones(stones, 25)
+    }
+
+    fn part2(stones: &Vec<usize>) -> usize {
+        count_stones(stones, 75)
+    }
+
+    /// Checks every stone on its own for the 25 blinks of part 1. Part 2 runs the same
+    /// memoised blinks for longer, which is beyond what the reference can simulate.
+    fn check(stones: &Vec<usize>) -> Option<Report> {
+        let mut report = Report::default();
+        for &stone in stones {
+            report.compare(
+                1,
+                1,
+                &stone.to_string(),
+                count_stones(&[stone], 25),
+                reference::stones_after(stone, 25),
+            );
+        }
+        Some(report)
+    }
+}
+
+fn blink(
+    previous_stones: &mut HashMap<usize, usize>,
+    map: &mut HashMap<usize, Vec<usize>>,
+) -> HashMap<usize, usize> {
+    let mut new_counts: HashMap<usize, usize> = HashMap::new();
+
+    // check each unique number on previous stones
+    for (s, stone_count) in previous_stones.iter_mut() {
+        let next_stones = map.entry(*s).or_insert_with(|| match s.split_digits() {
+            Some((left, right)) => vec![left, right],
+            None => vec![*s * 2024],
+        });
+
+        let count = new_counts.entry(next_stones[0]).or_insert(0);
+        *count += *stone_count;
+
+        if next_stones.len() == 2 {
+            let count = new_counts.entry(next_stones[1]).or_insert(0);
+            *count += *stone_count;
+        }
+    }
+
+    new_counts
+}
@@ -0,0 +1,24 @@
+//! Reference blinking that keeps every stone, to check the memoised `blink` against.
+
+/// The stones a single stone turns into after one blink.
+fn blink(stone: usize) -> Vec<usize> {
+    if stone == 0 {
+        return vec![1];
+    }
+    let digits = stone.to_string();
+    if digits.len().is_multiple_of(2) {
+        let (left, right) = digits.split_at(digits.len() / 2);
+        vec![left.parse().unwrap(), right.parse().unwrap()]
+    } else {
+        vec![stone * 2024]
+    }
+}
+
+/// Number of stones after `blinks` blinks, simulating every single stone.
+pub fn stones_after(stone: usize, blinks: usize) -> usize {
+    let mut stones = vec![stone];
+    for _ in 0..blinks {
+        stones = stones.into_iter().flat_map(blink).collect();
+    }
+    stones.len()
+}
@@ -0,0 +1 @@
+aoc_common::example_tests!(aoc_2024_day_11::Day11, 2024, 11, "data");
@@ -0,0 +1,179 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::search::Bfs;
+use aoc_common::{parse, Dir8, Grid, Image, Palette, ParseError, Pos, Solution};
+use log::{debug, info};
+
+#[derive(Clone)]
+pub struct Garden {
+    plants: Grid<String>,
+    plots: Vec<GardenPlot>,
+    costs_per_type: HashMap<String, usize>,
+}
+
+impl Garden {
+    fn new(contents: &str) -> Result<Self, ParseError> {
+        let costs_per_type = HashMap::<String, usize>::new();
+        let plots = Vec::<GardenPlot>::new();
+        let plants = parse::grid(contents, |cell| Some(cell.to_string()))?;
+
+        Ok(Self {
+            plants,
+            plots,
+            costs_per_type,
+        })
+    }
+
+    fn survey(&mut self) {
+        let mut visited = Grid::new(self.plants.width(), self.plants.height(), false);
+        for pos in self.plants.positions() {
+            if visited[pos] {
+                continue;
+            }
+            let plant_type = &self.plants[pos];
+            let mut plot = GardenPlot::new(plant_type.clone(), 0, 0);
+            for plant in Bfs::new(pos, |&plant| self.neighbours_of_same_type(plant)) {
+                visited[plant] = true;
+                plot.add_plant(plant);
+                plot.perimeter += 4 - self.neighbours_of_same_type(plant).count();
+                plot.area += 1;
+            }
+            self.plots.push(plot);
+        }
+    }
+
+    fn neighbours_of_same_type(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        let plant_type = &self.plants[pos];
+        self.plants
+            .neighbours4(pos)
+            .filter(move |&neighbour| self.plants[neighbour] == *plant_type)
+    }
+
+    fn calculate_costs(&mut self) -> usize {
+        for (i, plot) in self.plots.iter().enumerate() {
+            let cost = plot.area * plot.perimeter;
+            debug!(
+                "# {}. Plant: {}, Area: {}, Perimeter: {}, Cost: {}",
+                i, plot.plant_type, plot.area, plot.perimeter, cost
+            );
+            match self.costs_per_type.get_mut(&plot.plant_type) {
+                Some(plant_map) => *plant_map += cost,
+                None => {
+                    self.costs_per_type.insert(plot.plant_type.clone(), cost);
+                }
+            }
+        }
+
+        let total_costs = self
+            .costs_per_type
+            .iter()
+            .fold(0, |acc, (_, cost)| acc + cost);
+        info!("Total costs: {}", total_costs);
+        total_costs
+    }
+
+    fn calculate_bulk_discount(&mut self) -> usize {
+        let mut total_costs = 0;
+        for (i, plot) in self.plots.iter_mut().enumerate() {
+            // a plot has as many sides as it has corners
+            let sides = plot.count_corners();
+            let cost = plot.area * sides;
+            debug!(
+                "# {}. Plant: {}, Area: {}, Sides: {}, Cost: {}",
+                i, plot.plant_type, plot.area, sides, cost
+            );
+            total_costs += cost;
+        }
+        info!("Total costs with bulk discount: {}", total_costs);
+        total_costs
+    }
+}
+
+#[derive(Clone)]
+struct GardenPlot {
+    plant_type: String,
+    plants: HashSet<Pos>,
+    area: usize,
+    perimeter: usize,
+}
+
+impl GardenPlot {
+    fn new(plant_type: String, area: usize, perimeter: usize) -> Self {
+        Self {
+            plant_type,
+            plants: HashSet::new(),
+            area,
+            perimeter,
+        }
+    }
+
+    fn add_plant(&mut self, pos: Pos) {
+        self.plants.insert(pos);
+    }
+
+    fn count_corners(&self) -> usize {
+        let mut corners = 0;
+
+        for &plant in &self.plants {
+            let in_plot = |direction: Dir8| {
+                plant
+                    .step(direction)
+                    .is_some_and(|pos| self.plants.contains(&pos))
+            };
+            for diagonal in Dir8::DIAGONALS {
+                // the orthogonal neighbours on either side of the diagonal
+                let left = in_plot(diagonal.turn_left());
+                let right = in_plot(diagonal.turn_right());
+                let across = in_plot(diagonal);
+
+                // outer corner: both sides open; inner corner: both sides closed but diagonal open
+                if (!left && !right) || (left && right && !across) {
+                    corners += 1;
+                }
+            }
+        }
+
+        corners
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Garden;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Garden, ParseError> {
+        let contents = input.trim();
+        debug!("Plant garden:\n{}", contents);
+        Garden::new(contents)
+    }
+
+    fn part1(garden: &Garden) -> usize {
+        let mut garden = garden.clone();
+        garden.survey();
+        garden.calculate_costs()
+    }
+
+    fn part2(garden: &Garden) -> usize {
+        let mut garden = garden.clone();
+        garden.survey();
+        garden.calculate_bulk_discount()
+    }
+
+    /// Every garden plot in a colour of its own
+    fn image(garden: &Garden) -> Option<Image> {
+        let mut garden = garden.clone();
+        garden.survey();
+        let palette = Palette::categorical(garden.plots.len());
+
+        let mut plots = Grid::new(garden.plants.width(), garden.plants.height(), 0);
+        for (i, plot) in garden.plots.iter().enumerate() {
+            for &pos in &plot.plants {
+                plots[pos] = i;
+            }
+        }
+        Some(plots.to_image(|_, &i| palette.colour(i)))
+    }
+}
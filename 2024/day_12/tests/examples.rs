@@ -0,0 +1 @@
+aoc_common::example_tests!(aoc_2024_day_12::Day12, 2024, 12, "data");
@@ -0,0 +1,66 @@
+use aoc_common::{parse, ParseError, Solution};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    /// Levels of every report
+    type Input = Vec<Vec<i32>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+        input.lines().map(parse::numbers).collect()
+    }
+
+    fn part1(reports: &Vec<Vec<i32>>) -> usize {
+        reports
+            .iter()
+            .filter(|numbers| check_safety(numbers.to_vec()))
+            .count()
+    }
+
+    fn part2(reports: &Vec<Vec<i32>>) -> usize {
+        reports
+            .iter()
+            .filter(|numbers| check_safety_with_dampener(numbers.to_vec()))
+            .count()
+    }
+}
+
+fn check_safety(numbers: Vec<i32>) -> bool {
+    let safe_increasing = numbers.windows(2).all(is_safe_increasing);
+    let safe_decreasing = numbers.windows(2).all(is_safe_decreasing);
+
+    safe_increasing || safe_decreasing
+}
+
+fn check_safety_with_dampener(numbers: Vec<i32>) -> bool {
+    let mut safe_increasing: bool = false;
+    let mut safe_decreasing: bool = false;
+
+    for i in 0..numbers.len() {
+        let numbers_modified = numbers
+            .clone()
+            .into_iter()
+            .enumerate()
+            .filter(|(idx, _)| i != *idx)
+            .map(|(_, val)| val)
+            .collect::<Vec<i32>>();
+
+        safe_increasing = numbers_modified.windows(2).all(is_safe_increasing);
+        safe_decreasing = numbers_modified.windows(2).all(is_safe_decreasing);
+
+        if safe_increasing || safe_decreasing {
+            break;
+        }
+    }
+    safe_increasing || safe_decreasing
+}
+
+fn is_safe_increasing(pair: &[i32]) -> bool {
+    pair[1] > pair[0] && (pair[1] - pair[0]) > 0 && (pair[1] - pair[0]) < 4
+}
+
+fn is_safe_decreasing(pair: &[i32]) -> bool {
+    pair[0] > pair[1] && (pair[0] - pair[1]) > 0 && (pair[0] - pair[1]) < 4
+}
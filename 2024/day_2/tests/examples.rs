@@ -0,0 +1 @@
+aoc_common::example_tests!(aoc_2024_day_2::Day2, 2024, 2, "data");
@@ -0,0 +1,82 @@
+use aoc_common::{ParseError, Solution};
+use regex::Regex;
+
+#[derive(Debug)]
+pub enum Expression {
+    Mul(i64, i64),
+    Do,
+    Dont,
+}
+
+#[derive(PartialEq)]
+enum State {
+    On,
+    Off,
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    /// Instructions recovered from the corrupted memory, in order
+    type Input = Vec<Expression>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Expression>, ParseError> {
+        Ok(input.lines().flat_map(find_expressions).collect())
+    }
+
+    fn part1(expressions: &Vec<Expression>) -> i64 {
+        let pairs: Vec<(i64, i64)> = expressions
+            .iter()
+            .filter_map(|expr| match expr {
+                Expression::Mul(x, y) => Some((*x, *y)),
+                _ => None,
+            })
+            .collect();
+        multiply_and_sum(&pairs)
+    }
+
+    fn part2(expressions: &Vec<Expression>) -> i64 {
+        let mut sum_with_state: i64 = 0;
+        let mut state: State = State::On;
+        for expr in expressions {
+            match expr {
+                Expression::Mul(x, y) => {
+                    if state == State::On {
+                        sum_with_state += x * y;
+                    }
+                }
+                Expression::Do => state = State::On,
+                Expression::Dont => state = State::Off,
+            }
+        }
+        sum_with_state
+    }
+}
+
+fn find_expressions(input: &str) -> Vec<Expression> {
+    // operands have one to three digits, so longer numbers are corrupted memory
+    let re = Regex::new(r"(?P<mul>mul\s*\((\d{1,3})\s*,\s*(\d{1,3})\))|(?P<do>do\(\))|(?P<dont>don't\(\))")
+        .unwrap();
+
+    let mut expressions = Vec::new();
+
+    for caps in re.captures_iter(input) {
+        if caps.name("mul").is_some() {
+            let x = caps[2].parse().expect("matched 1-3 digits");
+            let y = caps[3].parse().expect("matched 1-3 digits");
+            expressions.push(Expression::Mul(x, y));
+        } else if caps.name("do").is_some() {
+            expressions.push(Expression::Do);
+        } else if caps.name("dont").is_some() {
+            expressions.push(Expression::Dont);
+        }
+    }
+
+    expressions
+}
+
+fn multiply_and_sum(pairs: &[(i64, i64)]) -> i64 {
+    pairs.iter().map(|(x, y)| x * y).sum()
+}
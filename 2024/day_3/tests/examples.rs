@@ -0,0 +1 @@
+aoc_common::example_tests!(aoc_2024_day_3::Day3, 2024, 3, "data");
@@ -0,0 +1,120 @@
+use aoc_common::{parse, Dir8, Grid, ParseError, Pos, Solution, Vec2};
+use log::debug;
+
+#[derive(Clone)]
+pub struct LetterGrid {
+    grid: Grid<char>,
+    xmas_hits: u32,
+    cross_mas_hits: u32,
+}
+
+impl LetterGrid {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let grid = parse::grid(input, Some)?;
+
+        debug!(
+            "Making LetterGrid with {} rows and {} columns",
+            grid.height(),
+            grid.width()
+        );
+
+        Ok(LetterGrid {
+            grid,
+            xmas_hits: 0,
+            cross_mas_hits: 0,
+        })
+    }
+
+    /// Whether the letter `offset` away from `pos` is `desired`.
+    fn check_letter(&self, pos: Pos, offset: Vec2, desired: char) -> bool {
+        self.grid.step(pos, offset).map(|pos| self.grid[pos]) == Some(desired)
+    }
+
+    fn find_xmas_patterns(&mut self) {
+        for pos in self.grid.positions() {
+            if self.grid[pos] == 'X' {
+                self.check_xmas_from_position(pos);
+            }
+        }
+    }
+
+    fn check_xmas_from_position(&mut self, pos: Pos) {
+        for direction in Dir8::ALL {
+            let word = self
+                .grid
+                .ray(pos, direction)
+                .take(4)
+                .map(|pos| self.grid[pos]);
+
+            if word.eq("XMAS".chars()) {
+                self.xmas_hits += 1;
+            }
+        }
+    }
+
+    fn find_cross_mas_patterns(&mut self) {
+        for pos in self.grid.positions() {
+            if self.grid[pos] == 'M' {
+                self.check_cross_mas_from_position(pos);
+            }
+        }
+    }
+
+    fn check_cross_mas_from_position(&mut self, base: Pos) {
+        // the A lies diagonally from the M
+        for direction in Dir8::DIAGONALS {
+            let offset = direction.offset();
+            // check for A
+            if !self.check_letter(base, offset, 'A') {
+                continue;
+            }
+
+            // need an S across
+            if !self.check_letter(base, offset * 2, 'S') {
+                continue;
+            }
+
+            let same_row = Vec2::new(offset.x * 2, 0);
+            let same_column = Vec2::new(0, offset.y * 2);
+            // check letter on same row
+            if self.check_letter(base, same_row, 'M') {
+                // need an S on the same column
+                if !self.check_letter(base, same_column, 'S') {
+                    continue;
+                }
+                self.cross_mas_hits += 1;
+            } else if self.check_letter(base, same_row, 'S') {
+                // need an M on the same column
+                if !self.check_letter(base, same_column, 'M') {
+                    continue;
+                }
+                self.cross_mas_hits += 1;
+            }
+        }
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = LetterGrid;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<LetterGrid, ParseError> {
+        LetterGrid::new(input)
+    }
+
+    fn part1(letter_grid: &LetterGrid) -> u32 {
+        let mut letter_grid = letter_grid.clone();
+        letter_grid.find_xmas_patterns();
+        letter_grid.xmas_hits
+    }
+
+    /// Part 2: checking every M means we find 2N patterns
+    fn part2(letter_grid: &LetterGrid) -> u32 {
+        let mut letter_grid = letter_grid.clone();
+        letter_grid.find_cross_mas_patterns();
+        letter_grid.cross_mas_hits / 2
+    }
+}
@@ -0,0 +1 @@
+aoc_common::example_tests!(aoc_2024_day_4::Day4, 2024, 4, "data");
@@ -0,0 +1,35 @@
+//! Random page ordering rules and updates for property tests.
+
+use aoc_common::random::Rng;
+
+/// Rules ordering every pair of up to 13 distinct pages in some random order, followed by
+/// `size` updates of an odd number of those pages, about half of them correctly ordered.
+pub fn print_queue(rng: &mut Rng, size: usize) -> String {
+    let mut pages: Vec<usize> = (10..100).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(3 + size.min(20) / 2);
+
+    let mut rules = Vec::new();
+    for (i, first) in pages.iter().enumerate() {
+        for second in &pages[i + 1..] {
+            rules.push(format!("{}|{}", first, second));
+        }
+    }
+    rng.shuffle(&mut rules);
+
+    let mut updates = Vec::new();
+    for _ in 0..size.max(1) {
+        let len = 2 * rng.below(pages.len().div_ceil(2)) + 1;
+        let mut picked: Vec<usize> = (0..pages.len()).collect();
+        rng.shuffle(&mut picked);
+        picked.truncate(len);
+        if rng.chance(0.5) {
+            // keep the pages in the order of the rules
+            picked.sort();
+        }
+        let update: Vec<String> = picked.iter().map(|&i| pages[i].to_string()).collect();
+        updates.push(update.join(","));
+    }
+
+    format!("{}\n\n{}\n", rules.join("\n"), updates.join("\n"))
+}
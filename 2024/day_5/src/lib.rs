@@ -0,0 +1,126 @@
+pub mod generate;
+pub mod reference;
+
+use aoc_common::digraph::Digraph;
+use aoc_common::oracle::Report;
+use aoc_common::{parse, ParseError, Solution};
+use log::warn;
+
+/// Page ordering rules and the updates to check against them.
+pub struct PrintQueue {
+    /// An edge from every page to the pages that must be printed after it
+    rules: Digraph<u16>,
+    /// Line of the first update in the input
+    updates_line: usize,
+    updates: Vec<Vec<u16>>,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = PrintQueue;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<PrintQueue, ParseError> {
+        let mut rules = Digraph::new();
+        let mut updates: Vec<Vec<u16>> = Vec::new();
+
+        let blocks = parse::blocks(input);
+        let (rule_lines, update_lines) = match blocks[..] {
+            [rule_lines, update_lines] => (rule_lines, update_lines),
+            _ => {
+                return Err(ParseError::new(
+                    "Expected ordering rules and updates separated by a blank line",
+                    input,
+                ))
+            }
+        };
+
+        for line in rule_lines.lines() {
+            match parse::separated(line, '|')?[..] {
+                [first, second] => rules.add_edge(first, second),
+                _ => return Err(ParseError::new("Expected a rule like `47|53`", line)),
+            }
+        }
+        for line in update_lines.lines() {
+            updates.push(parse::separated(line, ',')?);
+        }
+
+        // the blocks are slices of the input, so the updates start where their slice does
+        let updates_offset = update_lines.as_ptr() as usize - input.as_ptr() as usize;
+        Ok(PrintQueue {
+            rules,
+            updates_line: input[..updates_offset].matches('\n').count() + 1,
+            updates,
+        })
+    }
+
+    fn part1(queue: &PrintQueue) -> u32 {
+        queue
+            .updates
+            .iter()
+            .filter_map(|pages| middle_if_ordered(pages, &queue.rules))
+            .map(|middle_page| middle_page as u32)
+            .sum()
+    }
+
+    fn part2(queue: &PrintQueue) -> u32 {
+        queue
+            .updates
+            .iter()
+            .filter_map(|pages| middle_if_fixed(pages, &queue.rules))
+            .map(|middle_page_fixed| middle_page_fixed as u32)
+            .sum()
+    }
+
+    fn check(queue: &PrintQueue) -> Option<Report> {
+        let rules: Vec<(u16, u16)> = queue.rules.edges().map(|(&a, &b)| (a, b)).collect();
+        let mut report = Report::default();
+        for (i, pages) in queue.updates.iter().enumerate() {
+            let line = queue.updates_line + i;
+            let text: Vec<String> = pages.iter().map(u16::to_string).collect();
+            let text = text.join(",");
+            report.compare(
+                1,
+                line,
+                &text,
+                middle_if_ordered(pages, &queue.rules),
+                reference::middle_if_ordered(pages, &rules),
+            );
+            report.compare(
+                2,
+                line,
+                &text,
+                middle_if_fixed(pages, &queue.rules),
+                reference::middle_if_fixed(pages, &rules),
+            );
+        }
+        Some(report)
+    }
+}
+
+/// Middle page of the update if it is correctly ordered.
+fn middle_if_ordered(pages: &[u16], rules: &Digraph<u16>) -> Option<u16> {
+    if !rules.is_sorted(pages) {
+        return None;
+    }
+    pages.get(pages.len() / 2).copied()
+}
+
+/// Middle page of the update after ordering it, if it was not ordered to begin with.
+fn middle_if_fixed(pages: &[u16], rules: &Digraph<u16>) -> Option<u16> {
+    if rules.is_sorted(pages) {
+        return None;
+    }
+    match rules.topological_sort(pages) {
+        Ok(fixed) => fixed.get(fixed.len() / 2).copied(),
+        Err(cycle) => {
+            warn!(
+                "Cannot order update {:?}, the rules form a {}",
+                pages, cycle
+            );
+            None
+        }
+    }
+}
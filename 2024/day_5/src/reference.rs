@@ -0,0 +1,43 @@
+//! Reference ordering of updates, to check the topological sort of the rules against.
+
+fn must_precede(first: u16, second: u16, rules: &[(u16, u16)]) -> bool {
+    rules.contains(&(first, second))
+}
+
+/// Whether no rule puts a page of the update before one printed earlier.
+pub fn is_ordered(pages: &[u16], rules: &[(u16, u16)]) -> bool {
+    pages.iter().enumerate().all(|(i, &earlier)| {
+        pages[i + 1..]
+            .iter()
+            .all(|&later| !must_precede(later, earlier, rules))
+    })
+}
+
+/// The pages in an order obeying the rules, built by repeatedly taking a page that no
+/// rule puts after one of the remaining pages. `None` when the rules form a cycle.
+pub fn order(pages: &[u16], rules: &[(u16, u16)]) -> Option<Vec<u16>> {
+    let mut remaining = pages.to_vec();
+    let mut ordered = Vec::with_capacity(pages.len());
+    while !remaining.is_empty() {
+        let next = (0..remaining.len()).find(|&i| {
+            remaining
+                .iter()
+                .all(|&other| !must_precede(other, remaining[i], rules))
+        })?;
+        ordered.push(remaining.remove(next));
+    }
+    Some(ordered)
+}
+
+/// Middle page of the update if it is correctly ordered.
+pub fn middle_if_ordered(pages: &[u16], rules: &[(u16, u16)]) -> Option<u16> {
+    is_ordered(pages, rules).then(|| pages[pages.len() / 2])
+}
+
+/// Middle page of the update after ordering it, if it was not ordered to begin with.
+pub fn middle_if_fixed(pages: &[u16], rules: &[(u16, u16)]) -> Option<u16> {
+    if is_ordered(pages, rules) {
+        return None;
+    }
+    order(pages, rules).map(|ordered| ordered[ordered.len() / 2])
+}
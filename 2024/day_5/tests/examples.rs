@@ -0,0 +1 @@
+aoc_common::example_tests!(aoc_2024_day_5::Day5, 2024, 5, "data");
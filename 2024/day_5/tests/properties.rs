@@ -0,0 +1,66 @@
+//! The solver against sorting by the rules on random print queues.
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use aoc_2024_day_5::{generate, Day5};
+use aoc_common::testing::check_reference;
+
+/// Sums of the middle pages of the correctly ordered updates and of the fixed ones.
+fn middle_pages(input: &str) -> (u32, u32) {
+    let (rules, updates) = input.split_once("\n\n").unwrap();
+    let rules: HashSet<(u32, u32)> = rules
+        .lines()
+        .map(|rule| {
+            let (first, second) = rule.split_once('|').unwrap();
+            (first.parse().unwrap(), second.parse().unwrap())
+        })
+        .collect();
+    let order = |a: &u32, b: &u32| {
+        if rules.contains(&(*a, *b)) {
+            Ordering::Less
+        } else if rules.contains(&(*b, *a)) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    };
+
+    let (mut correct, mut fixed) = (0, 0);
+    for update in updates.lines() {
+        let pages: Vec<u32> = update
+            .split(',')
+            .map(|page| page.parse().unwrap())
+            .collect();
+        let mut sorted = pages.clone();
+        sorted.sort_by(order);
+        if sorted == pages {
+            correct += pages[pages.len() / 2];
+        } else {
+            fixed += sorted[sorted.len() / 2];
+        }
+    }
+    (correct, fixed)
+}
+
+#[test]
+fn part1_matches_reference() {
+    check_reference::<Day5, _>(
+        "2024 day 5",
+        1,
+        "middle pages",
+        generate::print_queue,
+        |input| middle_pages(input).0,
+    );
+}
+
+#[test]
+fn part2_matches_reference() {
+    check_reference::<Day5, _>(
+        "2024 day 5",
+        2,
+        "middle pages",
+        generate::print_queue,
+        |input| middle_pages(input).1,
+    );
+}
@@ -0,0 +1,265 @@
+use std::collections::HashSet;
+
+use aoc_common::{parse, Dir4, Frame, Grid, Image, ParseError, Pos, Rgb, Simulation, Solution};
+use log::debug;
+use rayon::prelude::*;
+
+#[derive(Clone, PartialEq)]
+pub struct Guard {
+    position: Pos,
+    direction: Dir4,
+    left_area: bool,
+    path: HashSet<Pos>,
+    path_directional: HashSet<(Pos, Dir4)>,
+}
+
+impl Guard {
+    fn new(position: Pos, direction: Dir4) -> Self {
+        Guard {
+            position,
+            direction,
+            left_area: false,
+            path: HashSet::new(),
+            path_directional: HashSet::new(),
+        }
+    }
+
+    fn step(&mut self, map: &mut Map) {
+        // check bounds
+        let Some(next) = map.cells.step(self.position, self.direction) else {
+            self.left_area = true;
+            return;
+        };
+
+        // check for obstacle
+        if map.cells[next].is_obstacle {
+            self.direction = self.direction.turn_right();
+            return;
+        }
+
+        map.cells[next].add_guard();
+        map.cells[self.position].remove_guard();
+        map.cells[next].cross(self.direction);
+
+        self.position = next;
+        self.path.insert(self.position);
+        self.path_directional
+            .insert((self.position, self.direction));
+    }
+
+    fn check_loop(&self) -> bool {
+        self.position
+            .step(self.direction)
+            .is_some_and(|next| self.path_directional.contains(&(next, self.direction)))
+    }
+}
+
+#[derive(Clone, Default, PartialEq)]
+pub struct Cell {
+    symbol: char,
+    is_obstacle: bool,
+    has_guard: bool,
+    directions_crossed: HashSet<Dir4>,
+}
+
+impl Cell {
+    fn new(value: char, has_guard: bool) -> Self {
+        let is_obstacle: bool = value == '#';
+
+        Cell {
+            symbol: value,
+            is_obstacle,
+            has_guard,
+            directions_crossed: HashSet::new(),
+        }
+    }
+
+    fn remove_guard(&mut self) {
+        self.has_guard = false;
+    }
+    fn add_guard(&mut self) {
+        self.has_guard = true;
+    }
+
+    fn cross(&mut self, direction: Dir4) {
+        self.directions_crossed.insert(direction);
+    }
+
+    fn get_char(&self) -> char {
+        if self.is_obstacle {
+            '#'
+        } else if self.has_guard {
+            'G'
+        } else {
+            let crossed = &self.directions_crossed;
+            match (
+                crossed.iter().any(|direction| direction.is_horizontal()),
+                crossed.iter().any(|direction| !direction.is_horizontal()),
+            ) {
+                (true, true) => '+',
+                (true, false) => '-',
+                (false, true) => '|',
+                (false, false) => '.',
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Map {
+    cells: Grid<Cell>,
+}
+
+impl Map {
+    fn new(cells: Grid<Cell>) -> Self {
+        Map { cells }
+    }
+
+    fn add_obstacle(&mut self, position: Pos) {
+        self.cells[position].symbol = 'O';
+        self.cells[position].is_obstacle = true;
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    /// The lab map and the guard at her starting position
+    type Input = (Map, Guard);
+    type Answer1 = usize;
+    type Answer2 = u16;
+
+    fn parse(input: &str) -> Result<(Map, Guard), ParseError> {
+        parse_map(input)
+    }
+
+    fn part1((map, guard): &(Map, Guard)) -> usize {
+        let (mut map, mut guard) = (map.clone(), guard.clone());
+        patrol(&mut map, &mut guard);
+        guard.path.len()
+    }
+
+    fn part2((map, guard): &(Map, Guard)) -> u16 {
+        loop_obstacles(map, guard).len() as u16
+    }
+
+    fn simulation((map, guard): &(Map, Guard)) -> Option<Box<dyn Simulation>> {
+        Some(Box::new(PatrolSimulation {
+            map: map.clone(),
+            guard: guard.clone(),
+        }))
+    }
+
+    /// The guard's path, her starting position and the obstacles that would trap her
+    fn image((map, guard): &(Map, Guard)) -> Option<Image> {
+        let obstacles = loop_obstacles(map, guard);
+        let (mut patrolled, mut end) = (map.clone(), guard.clone());
+        patrol(&mut patrolled, &mut end);
+
+        let mut image = patrolled.cells.to_image(|_, cell| {
+            if cell.is_obstacle {
+                Rgb::GREY
+            } else if cell.directions_crossed.is_empty() {
+                Rgb::BLACK
+            } else {
+                Rgb::BLUE
+            }
+        });
+        for position in obstacles {
+            image[position] = Rgb::RED;
+        }
+        image[guard.position] = Rgb::GREEN;
+        Some(image)
+    }
+}
+
+/// The guard's patrol of part 1, one step or turn at a time.
+struct PatrolSimulation {
+    map: Map,
+    guard: Guard,
+}
+
+impl Simulation for PatrolSimulation {
+    fn step(&mut self) -> bool {
+        if self.guard.left_area {
+            return false;
+        }
+        self.guard.step(&mut self.map);
+        true
+    }
+
+    fn frame(&self) -> Frame {
+        let status = if self.guard.left_area {
+            format!(
+                "guard left the area after visiting {} cells",
+                self.guard.path.len()
+            )
+        } else {
+            format!("visited {} cells", self.guard.path.len())
+        };
+        Frame {
+            text: render(&self.map, &self.guard),
+            focus: Some(self.guard.position),
+            status,
+        }
+    }
+}
+
+fn parse_map(input: &str) -> Result<(Map, Guard), ParseError> {
+    let cells = parse::grid(input, |ch| match ch {
+        '.' | '#' => Some(Cell::new(ch, false)),
+        _ => Dir4::from_arrow(ch).map(|_| Cell::new(ch, true)),
+    })?;
+
+    let position = cells
+        .find(|cell| cell.has_guard)
+        .ok_or_else(|| ParseError::new("No guard on the map", input))?;
+    let direction = Dir4::from_arrow(cells[position].symbol).unwrap();
+
+    Ok((Map::new(cells), Guard::new(position, direction)))
+}
+
+/// Positions on the guard's path where an extra obstacle would make her walk in a loop.
+fn loop_obstacles(map: &Map, guard: &Guard) -> Vec<Pos> {
+    let (mut map, mut guard) = (map.clone(), guard.clone());
+    let initial_position = guard.position;
+    patrol(&mut map, &mut guard);
+
+    guard
+        .path
+        .par_iter()
+        .filter(|&&position| {
+            let mut map_clone = map.clone();
+            let mut new_guard = Guard::new(initial_position, Dir4::Up);
+
+            // add obstacle
+            map_clone.add_obstacle(position);
+
+            // check for loop
+            while !new_guard.left_area {
+                new_guard.step(&mut map_clone);
+                if new_guard.check_loop() {
+                    return true;
+                }
+            }
+            false
+        })
+        .copied()
+        .collect()
+}
+
+fn patrol(map: &mut Map, guard: &mut Guard) {
+    while !guard.left_area {
+        guard.step(map);
+    }
+}
+
+fn render(map: &Map, guard: &Guard) -> String {
+    let rendered = map.cells.render(|_, cell| cell.get_char());
+    rendered.replace('G', &guard.direction.arrow().to_string())
+}
+
+#[allow(dead_code)]
+fn print(map: &Map, guard: &Guard) {
+    debug!("\n{}", render(map, guard));
+}
@@ -0,0 +1 @@
+aoc_common::example_tests!(aoc_2024_day_6::Day6, 2024, 6, "data");
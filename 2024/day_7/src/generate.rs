@@ -0,0 +1,29 @@
+//! Random calibration equations for property tests.
+
+use aoc_common::random::Rng;
+
+/// `size` equations of up to six operands below 100, zero included. About half of them are built from
+/// random operators, including concatenation, and so are solvable; the others are
+/// usually not.
+pub fn equations(rng: &mut Rng, size: usize) -> String {
+    let mut equations = String::new();
+    for _ in 0..size.max(1) {
+        let operands: Vec<usize> = (0..rng.between(1, 6)).map(|_| rng.between(0, 99)).collect();
+
+        let mut result = operands[0];
+        for &operand in &operands[1..] {
+            result = match rng.below(3) {
+                0 => result + operand,
+                1 => result * operand,
+                _ => format!("{}{}", result, operand).parse().unwrap(),
+            };
+        }
+        if rng.chance(0.5) {
+            result += rng.between(1, 10);
+        }
+
+        let operands: Vec<String> = operands.iter().map(usize::to_string).collect();
+        equations += &format!("{}: {}\n", result, operands.join(" "));
+    }
+    equations
+}
@@ -0,0 +1,143 @@
+pub mod generate;
+pub mod reference;
+
+use aoc_common::digits::Digits;
+use aoc_common::oracle::Report;
+use aoc_common::{parse, ParseError, Solution};
+use log::debug;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    /// Test value and operands of every calibration equation
+    type Input = Vec<(usize, Vec<usize>)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                let (result, operands) = parse::key_values(line, ':')?;
+                if operands.is_empty() {
+                    return Err(ParseError::new("Missing operands", line));
+                }
+                Ok((result, operands))
+            })
+            .collect()
+    }
+
+    fn part1(equations: &Self::Input) -> usize {
+        let mut sum_solvable_results = 0;
+        for (result, factors) in equations {
+            if subdivide((*result, factors)) {
+                sum_solvable_results += result;
+            }
+        }
+        sum_solvable_results
+    }
+
+    fn part2(equations: &Self::Input) -> usize {
+        let mut sum_solvable_results_with_concatenation = 0;
+        for (result, factors) in equations {
+            if subdivide_with_concatenation((*result, factors)) {
+                debug!("Solved with concatenation: {}: {:?}", result, factors);
+                sum_solvable_results_with_concatenation += result;
+            }
+        }
+        sum_solvable_results_with_concatenation
+    }
+
+    fn check(equations: &Self::Input) -> Option<Report> {
+        let mut report = Report::default();
+        for (i, (result, operands)) in equations.iter().enumerate() {
+            let operands_text: Vec<String> = operands.iter().map(usize::to_string).collect();
+            let line = format!("{}: {}", result, operands_text.join(" "));
+            report.compare(
+                1,
+                i + 1,
+                &line,
+                subdivide((*result, operands)),
+                reference::solvable(*result, operands, false),
+            );
+            report.compare(
+                2,
+                i + 1,
+                &line,
+                subdivide_with_concatenation((*result, operands)),
+                reference::solvable(*result, operands, true),
+            );
+        }
+        Some(report)
+    }
+}
+
+fn subdivide(equation: (usize, &[usize])) -> bool {
+    let (result, factors) = equation;
+    if factors.len() == 1 {
+        return result == factors[0];
+    }
+
+    let k = factors[factors.len() - 1];
+    let remaining = &factors[..factors.len() - 1];
+
+    // multiplying by zero gives zero whatever came before, and adding it changes nothing
+    if k == 0 {
+        return result == 0 || subdivide((result, remaining));
+    }
+
+    // if k is a factor, then continue to subdivide
+    if result % k == 0 {
+        if subdivide((result / k, remaining)) {
+            return true;
+        }
+        if k > result {
+            return false;
+        }
+        // see if we can solve by subtracting at this step instead
+        return subdivide((result - k, remaining));
+    }
+
+    // else, subtract k and go one deeper
+    if result >= k {
+        return subdivide((result - k, remaining));
+    }
+
+    false
+}
+
+fn subdivide_with_concatenation(equation: (usize, &[usize])) -> bool {
+    let (result, factors) = equation;
+    if factors.len() == 1 {
+        return result == factors[0];
+    }
+
+    let k = factors[factors.len() - 1];
+    let remaining = &factors[..factors.len() - 1];
+
+    if k == 0 && result == 0 {
+        return true;
+    }
+    if can_divide(result, k) && subdivide_with_concatenation((result / k, remaining)) {
+        return true;
+    }
+    if can_subtract(result, k) && subdivide_with_concatenation((result - k, remaining)) {
+        return true;
+    }
+    if let Some(decatenated) = result.deconcat(k) {
+        debug!("Decatenating {} with {} into {}", result, k, decatenated);
+        if subdivide_with_concatenation((decatenated, remaining)) {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn can_divide(number: usize, divisor: usize) -> bool {
+    divisor != 0 && number.is_multiple_of(divisor)
+}
+
+fn can_subtract(number: usize, subtrahend: usize) -> bool {
+    number >= subtrahend
+}
@@ -0,0 +1,30 @@
+//! Brute force reference for the equations, to check the pruning in `subdivide` against.
+
+/// Whether some choice of operators, evaluated left to right, gives `result`, trying
+/// every combination of `+`, `*` and, with `concatenation`, `||`.
+pub fn solvable(result: usize, operands: &[usize], concatenation: bool) -> bool {
+    let operators: u32 = if concatenation { 3 } else { 2 };
+    let combinations = operators.pow(operands.len() as u32 - 1);
+    (0..combinations).any(|mut combination| {
+        let mut value = Some(operands[0] as u128);
+        for &operand in &operands[1..] {
+            let operand = operand as u128;
+            value = value.and_then(|value| match combination % operators {
+                0 => value.checked_add(operand),
+                1 => value.checked_mul(operand),
+                _ => concatenate(value, operand),
+            });
+            combination /= operators;
+        }
+        value == Some(result as u128)
+    })
+}
+
+/// The digits of `left` followed by those of `right`, unless that overflows.
+fn concatenate(left: u128, right: u128) -> Option<u128> {
+    let mut shift = 10;
+    while shift <= right {
+        shift *= 10;
+    }
+    left.checked_mul(shift)?.checked_add(right)
+}
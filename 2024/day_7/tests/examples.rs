@@ -0,0 +1 @@
+aoc_common::example_tests!(aoc_2024_day_7::Day7, 2024, 7, "data");
@@ -0,0 +1,46 @@
+//! The solver against trying every combination of operators on random equations.
+
+use aoc_2024_day_7::reference::solvable;
+use aoc_2024_day_7::{generate, Day7};
+use aoc_common::testing::check_reference;
+
+fn calibration(input: &str, concatenation: bool) -> usize {
+    input
+        .lines()
+        .map(|line| {
+            let (result, operands) = line.split_once(": ").unwrap();
+            let result: usize = result.parse().unwrap();
+            let operands: Vec<usize> = operands
+                .split(' ')
+                .map(|operand| operand.parse().unwrap())
+                .collect();
+            if solvable(result, &operands, concatenation) {
+                result
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+#[test]
+fn part1_matches_reference() {
+    check_reference::<Day7, _>(
+        "2024 day 7",
+        1,
+        "calibration",
+        generate::equations,
+        |input| calibration(input, false),
+    );
+}
+
+#[test]
+fn part2_matches_reference() {
+    check_reference::<Day7, _>(
+        "2024 day 7",
+        2,
+        "calibration",
+        generate::equations,
+        |input| calibration(input, true),
+    );
+}
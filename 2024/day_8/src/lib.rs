@@ -0,0 +1,143 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{parse, Grid, Image, Palette, ParseError, Pos, Rgb, Solution};
+
+#[derive(Debug)]
+pub struct CityGrid {
+    map: Grid<char>,
+    antennas: HashMap<char, Vec<Pos>>,
+}
+
+impl CityGrid {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let mut grid = Self {
+            map: parse::grid(input, Some)?,
+            antennas: HashMap::new(),
+        };
+
+        // Parse input and populate antennas
+        let antennas: Vec<(Pos, char)> = grid
+            .map
+            .iter()
+            .filter(|(_, &c)| c != '.')
+            .map(|(pos, &c)| (pos, c))
+            .collect();
+        for (pos, c) in antennas {
+            grid.add_antenna(c, pos);
+        }
+
+        Ok(grid)
+    }
+
+    fn add_antenna(&mut self, antenna_type: char, pos: Pos) {
+        self.antennas.entry(antenna_type).or_default().push(pos);
+    }
+
+    fn calculate_all_antinodes(&self) -> HashSet<Pos> {
+        let mut antinodes = HashSet::new();
+        for positions in self.antennas.values() {
+            for &pos1 in positions {
+                for &pos2 in positions {
+                    if pos1 != pos2 {
+                        if let Some(new_antinodes) = self.calculate_antinodes(pos1, pos2) {
+                            antinodes.extend(new_antinodes);
+                        }
+                    }
+                }
+            }
+        }
+        antinodes
+    }
+
+    fn calculate_all_harmonic_antinodes(&self) -> HashSet<Pos> {
+        let mut harmonic_antinodes = self.calculate_all_antinodes();
+        for positions in self.antennas.values() {
+            for &pos1 in positions {
+                for &pos2 in positions {
+                    if pos1 != pos2 {
+                        if let Some(new_harmonic_antinodes) =
+                            self.calculate_harmonic_antinodes(pos1, pos2)
+                        {
+                            harmonic_antinodes.extend(new_harmonic_antinodes);
+                        }
+                    }
+                }
+            }
+        }
+        harmonic_antinodes
+    }
+
+    fn calculate_antinodes(&self, a: Pos, b: Pos) -> Option<Vec<Pos>> {
+        // vector from a to b
+        let d = b - a;
+
+        // antinodes at a - d and b + d, if they are on the map
+        let result: Vec<Pos> = [self.map.step(a, -d), self.map.step(b, d)]
+            .into_iter()
+            .flatten()
+            .collect();
+
+        if result.is_empty() {
+            None
+        } else {
+            Some(result)
+        }
+    }
+
+    fn calculate_harmonic_antinodes(&self, a: Pos, b: Pos) -> Option<Vec<Pos>> {
+        // smallest step from a towards b that stays on whole positions
+        let d = (b - a).reduced();
+
+        // both rays start at a itself, which the other pair of a and b covers
+        let forwards = self.map.ray(a, d).skip(1);
+        let backwards = self.map.ray(a, -d).skip(1);
+        let result: Vec<Pos> = forwards.chain(backwards).collect();
+
+        if result.is_empty() {
+            None
+        } else {
+            Some(result)
+        }
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = CityGrid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<CityGrid, ParseError> {
+        CityGrid::new(input)
+    }
+
+    fn part1(city_grid: &CityGrid) -> usize {
+        city_grid.calculate_all_antinodes().len()
+    }
+
+    fn part2(city_grid: &CityGrid) -> usize {
+        city_grid.calculate_all_harmonic_antinodes().len()
+    }
+
+    /// Antennas coloured by frequency over the antinodes of both parts
+    fn image(city_grid: &CityGrid) -> Option<Image> {
+        let antinodes = city_grid.calculate_all_antinodes();
+        let harmonic_antinodes = city_grid.calculate_all_harmonic_antinodes();
+        let mut frequencies: Vec<char> = city_grid.antennas.keys().copied().collect();
+        frequencies.sort_unstable();
+        let palette = Palette::categorical(frequencies.len());
+
+        Some(city_grid.map.to_image(|pos, &c| {
+            if let Ok(i) = frequencies.binary_search(&c) {
+                palette.colour(i)
+            } else if antinodes.contains(&pos) {
+                Rgb::WHITE
+            } else if harmonic_antinodes.contains(&pos) {
+                Rgb::GREY
+            } else {
+                Rgb::BLACK
+            }
+        }))
+    }
+}
@@ -0,0 +1 @@
+aoc_common::example_tests!(aoc_2024_day_8::Day8, 2024, 8, "data");
@@ -0,0 +1,21 @@
+//! Random disk maps for property tests.
+
+use aoc_common::random::Rng;
+
+/// A disk map of `size` files, each up to 9 blocks long and followed by up to 9 free
+/// blocks, except for the last file.
+pub fn disk_map(rng: &mut Rng, size: usize) -> String {
+    let mut map = String::new();
+    for file in 0..size.max(1) {
+        if file > 0 {
+            map.push(digit(rng.between(0, 9)));
+        }
+        map.push(digit(rng.between(1, 9)));
+    }
+    map.push('\n');
+    map
+}
+
+fn digit(n: usize) -> char {
+    char::from_digit(n as u32, 10).unwrap()
+}
@@ -0,0 +1,270 @@
+pub mod generate;
+
+use std::collections::BTreeMap;
+
+use aoc_common::extent::{Extent, ExtentAllocator, Policy};
+use aoc_common::{Frame, ParseError, Pos, Simulation, Solution};
+use log::{debug, trace};
+
+#[derive(Clone, Debug)]
+pub struct DiskMap {
+    contiguous_files: BTreeMap<usize, (usize, usize)>,
+    free_space: ExtentAllocator,
+}
+
+impl DiskMap {
+    fn new(contents: &str) -> Result<Self, ParseError> {
+        let mut contiguous_files = BTreeMap::new();
+
+        let mut is_file = true;
+        let mut file_id = 0;
+        let mut index = 0;
+        for (i, char) in contents.char_indices() {
+            match char.to_digit(10) {
+                Some(count) => {
+                    let count = count as usize;
+                    if is_file {
+                        contiguous_files.insert(file_id, (index, count));
+                        file_id += 1;
+                    }
+                    is_file = !is_file;
+                    index += count;
+                }
+                None => {
+                    return Err(ParseError::new(
+                        "Invalid digit",
+                        &contents[i..i + char.len_utf8()],
+                    ));
+                }
+            }
+        }
+
+        let mut free_space = ExtentAllocator::new(index);
+        for &(pos, len) in contiguous_files.values() {
+            free_space.claim(Extent::new(pos, len));
+        }
+
+        Ok(DiskMap {
+            contiguous_files,
+            free_space,
+        })
+    }
+    fn print_layout_contiguous(&self) -> String {
+        let max_pos = self
+            .contiguous_files
+            .values()
+            .map(|(pos, len)| pos + len)
+            .max()
+            .unwrap_or(0);
+
+        let mut result = String::with_capacity(max_pos);
+        let mut current_pos = 0;
+
+        while current_pos < max_pos {
+            // Check if there's a file at current position
+            if let Some((&file_id, &(_start_pos, len))) = self
+                .contiguous_files
+                .iter()
+                .find(|(_, &(pos, _))| pos == current_pos)
+            {
+                result.push_str(&format!("[{}]", file_id).repeat(len));
+                current_pos += len;
+            }
+            // Check if there's free space at current position
+            else if let Some(free) = self.free_space.free_extent_at(current_pos) {
+                result.push_str(&".".repeat(free.end() - current_pos));
+                current_pos = free.end();
+            }
+            // If neither found, move to next position
+            else {
+                current_pos += 1;
+            }
+        }
+        result
+    }
+
+    /// Moves file blocks from the end of the disk into the lowest free blocks until there
+    /// are no gaps, splitting files where they do not fit. Returns the extents every file
+    /// ends up in.
+    fn defrag(&mut self) -> Vec<(usize, Extent)> {
+        let mut fragments = Vec::new();
+        let files: Vec<(usize, (usize, usize))> = self
+            .contiguous_files
+            .iter()
+            .rev()
+            .map(|(&id, &file)| (id, file))
+            .collect();
+
+        for (file_id, (file_pos, file_len)) in files {
+            // the whole file moves if there is room for it before its start, and otherwise
+            // as much of its end as fills up the rest of the gaps
+            let moving =
+                match self
+                    .free_space
+                    .allocate_below(file_len, Policy::LowestAddress, file_pos)
+                {
+                    Some(extents) => extents,
+                    None => {
+                        let free_below: usize = self
+                            .free_space
+                            .free_extents()
+                            .take_while(|free| free.start < file_pos)
+                            .map(|free| free.end().min(file_pos) - free.start)
+                            .sum();
+                        self.free_space
+                            .allocate_below(free_below, Policy::LowestAddress, file_pos)
+                            .expect("the free blocks before the file were just counted")
+                    }
+                };
+
+            let moved: usize = moving.iter().map(|extent| extent.len).sum();
+            debug!(
+                "Moving {} blocks of file {} to {:?}",
+                moved, file_id, moving
+            );
+            self.free_space
+                .free(Extent::new(file_pos + file_len - moved, moved));
+            fragments.extend(moving.into_iter().map(|extent| (file_id, extent)));
+            fragments.push((file_id, Extent::new(file_pos, file_len - moved)));
+        }
+        fragments
+    }
+
+    fn defrag_contiguous(&mut self) {
+        // Iterate through files in reverse order of position
+        let files_to_process: Vec<usize> = self.contiguous_files.keys().rev().copied().collect();
+
+        for file_id in files_to_process {
+            self.compact_file(file_id);
+        }
+    }
+
+    /// Moves a file to the leftmost free space before it that can hold it, returning its
+    /// new position if it moved.
+    fn compact_file(&mut self, file_id: usize) -> Option<usize> {
+        let (file_pos, file_len) = self.contiguous_files[&file_id];
+
+        // Find suitable free space
+        debug!("Processing file {} at {}", file_id, file_pos);
+        let free_start = self
+            .free_space
+            .allocate_below(file_len, Policy::FirstFit, file_pos)?
+            .first()?
+            .start;
+
+        // Move file to new location
+        debug!(
+            "Moving file {} from {} to {}",
+            file_id, file_pos, free_start
+        );
+        self.contiguous_files
+            .insert(file_id, (free_start, file_len));
+        self.free_space.free(Extent::new(file_pos, file_len));
+        Some(free_start)
+    }
+
+    fn calculate_checksum(fragments: &[(usize, Extent)]) -> usize {
+        fragments
+            .iter()
+            .flat_map(|&(id, extent)| (extent.start..extent.end()).map(move |i| i * id))
+            .inspect(|&product| trace!("checksum term {}", product))
+            .sum::<usize>()
+    }
+
+    fn calculate_checksum_contiguous(&self) -> usize {
+        self.contiguous_files
+            .iter()
+            .flat_map(|(&id, &(index, len))| (index..index + len).map(move |i| i * id))
+            .sum::<usize>()
+    }
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = DiskMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<DiskMap, ParseError> {
+        DiskMap::new(input.trim())
+    }
+
+    fn part1(disk_map: &DiskMap) -> usize {
+        let mut disk_map = disk_map.clone();
+        debug!("Initial layout:\n{}", disk_map.print_layout_contiguous());
+        let fragments = disk_map.defrag();
+        DiskMap::calculate_checksum(&fragments)
+    }
+
+    fn part2(disk_map: &DiskMap) -> usize {
+        let mut disk_map = disk_map.clone();
+        debug!("Initial layout:\n{}", disk_map.print_layout_contiguous());
+        disk_map.defrag_contiguous();
+        debug!("Defragged layout:\n{}", disk_map.print_layout_contiguous());
+        disk_map.calculate_checksum_contiguous()
+    }
+
+    fn simulation(disk_map: &DiskMap) -> Option<Box<dyn Simulation>> {
+        Some(Box::new(DefragSimulation {
+            disk_map: disk_map.clone(),
+            pending: disk_map.contiguous_files.keys().copied().collect(),
+            moved: 0,
+            last_moved: None,
+        }))
+    }
+}
+
+/// Blocks shown per line when animating, as the disk does not fit on a single one.
+const FRAME_WIDTH: usize = 100;
+
+/// The whole-file compaction of part 2, considering one file per step.
+struct DefragSimulation {
+    disk_map: DiskMap,
+    /// Files still to consider, the next one last
+    pending: Vec<usize>,
+    moved: usize,
+    last_moved: Option<usize>,
+}
+
+impl Simulation for DefragSimulation {
+    fn step(&mut self) -> bool {
+        let Some(file_id) = self.pending.pop() else {
+            return false;
+        };
+        if let Some(pos) = self.disk_map.compact_file(file_id) {
+            self.moved += 1;
+            self.last_moved = Some(pos);
+        }
+        true
+    }
+
+    fn frame(&self) -> Frame {
+        // files are drawn by the last base-36 digit of their id
+        let mut blocks = vec!['.'; self.disk_map.free_space.capacity()];
+        for (&id, &(pos, len)) in &self.disk_map.contiguous_files {
+            let symbol = char::from_digit((id % 36) as u32, 36).unwrap();
+            blocks[pos..pos + len].fill(symbol);
+        }
+        let text = blocks
+            .chunks(FRAME_WIDTH)
+            .map(|line| line.iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let files = self.disk_map.contiguous_files.len();
+        Frame {
+            text,
+            focus: self
+                .last_moved
+                .map(|pos| Pos::new(pos % FRAME_WIDTH, pos / FRAME_WIDTH)),
+            status: format!(
+                "considered {}/{} files, moved {}, checksum {}",
+                files - self.pending.len(),
+                files,
+                self.moved,
+                self.disk_map.calculate_checksum_contiguous()
+            ),
+        }
+    }
+}
@@ -0,0 +1 @@
+aoc_common::example_tests!(aoc_2024_day_9::Day9, 2024, 9, "data");
@@ -0,0 +1,68 @@
+//! The solver against a block-by-block reference on random disk maps.
+
+use aoc_2024_day_9::{generate, Day9};
+use aoc_common::testing::check_reference;
+
+/// The file id in every block of the disk, `None` for free space.
+fn blocks(input: &str) -> Vec<Option<usize>> {
+    let mut blocks = Vec::new();
+    for (i, c) in input.trim().chars().enumerate() {
+        let len = c.to_digit(10).unwrap() as usize;
+        let content = if i % 2 == 0 { Some(i / 2) } else { None };
+        blocks.extend(std::iter::repeat_n(content, len));
+    }
+    blocks
+}
+
+fn checksum(blocks: &[Option<usize>]) -> usize {
+    blocks
+        .iter()
+        .enumerate()
+        .map(|(i, block)| i * block.unwrap_or(0))
+        .sum()
+}
+
+/// Moves the last file block to the first free block until there are no gaps.
+fn compact_blocks(input: &str) -> usize {
+    let mut blocks = blocks(input);
+    loop {
+        let free = blocks.iter().position(Option::is_none);
+        let last = blocks.iter().rposition(Option::is_some);
+        match (free, last) {
+            (Some(free), Some(last)) if free < last => blocks.swap(free, last),
+            _ => break,
+        }
+    }
+    checksum(&blocks)
+}
+
+/// Moves every file, highest id first, to the first gap to its left that fits it.
+fn compact_files(input: &str) -> usize {
+    let mut blocks = blocks(input);
+    let max_id = blocks.iter().flatten().max().copied().unwrap_or(0);
+    for id in (0..=max_id).rev() {
+        let start = blocks.iter().position(|&b| b == Some(id)).unwrap();
+        let len = blocks.iter().filter(|&&b| b == Some(id)).count();
+        let gap = (0..start).find(|&i| blocks[i..i + len].iter().all(Option::is_none));
+        if let Some(gap) = gap {
+            for i in 0..len {
+                blocks.swap(gap + i, start + i);
+            }
+        }
+    }
+    checksum(&blocks)
+}
+
+#[test]
+fn part1_matches_reference() {
+    check_reference::<Day9, _>("2024 day 9", 1, "checksum", generate::disk_map, |input| {
+        compact_blocks(input)
+    });
+}
+
+#[test]
+fn part2_matches_reference() {
+    check_reference::<Day9, _>("2024 day 9", 2, "checksum", generate::disk_map, |input| {
+        compact_files(input)
+    });
+}
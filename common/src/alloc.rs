@@ -0,0 +1,130 @@
+//! A counting global allocator, to see how much memory the solvers use.
+//!
+//! Counting is opt-in: a binary installs [`CountingAllocator`] as its global allocator,
+//! after which [`measure`] and [`profile`] report what ran in between.
+//!
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOCATOR: aoc_common::alloc::CountingAllocator = aoc_common::alloc::CountingAllocator;
+//! ```
+//!
+//! The counters are shared by all threads, so only measure one thing at a time.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{parse_input, ParseError, Solution};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting allocations and the bytes in use.
+pub struct CountingAllocator;
+
+fn record_allocation(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+fn record_deallocation(size: usize) {
+    LIVE.fetch_sub(size, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_deallocation(layout.size());
+    }
+
+    /// Counted as a new allocation followed by freeing the old one.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record_allocation(new_size);
+            record_deallocation(layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// Whether [`CountingAllocator`] is the global allocator, and so whether counts mean
+/// anything.
+pub fn is_counting() -> bool {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    drop(black_box(Box::new(0u64)));
+    ALLOCATIONS.load(Ordering::Relaxed) != before
+}
+
+/// Memory used while running something.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations, including reallocations
+    pub allocations: usize,
+    /// Bytes requested over all allocations
+    pub bytes: usize,
+    /// Most bytes in use at once, above what was in use beforehand
+    pub peak: usize,
+}
+
+/// Runs `f`, counting the memory it uses.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, AllocStats) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+
+    let result = f();
+
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: ALLOCATED.load(Ordering::Relaxed) - bytes,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+    };
+    (result, stats)
+}
+
+/// Memory used by each stage of a single run.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoryUse {
+    pub parse: AllocStats,
+    pub part1: AllocStats,
+    pub part2: AllocStats,
+}
+
+/// Parses `input` and solves both parts once, counting the memory of every stage.
+pub fn profile<S: Solution>(input: &str) -> Result<MemoryUse, ParseError> {
+    let (parsed, parse) = measure(|| parse_input::<S>(black_box(input)));
+    let parsed = parsed?;
+    // answers are dropped outside the measurement, only the solving counts
+    let (answer1, part1) = measure(|| black_box(S::part1(&parsed)));
+    drop(answer1);
+    let (answer2, part2) = measure(|| black_box(S::part2(&parsed)));
+    drop(answer2);
+
+    Ok(MemoryUse {
+        parse,
+        part1,
+        part2,
+    })
+}
@@ -0,0 +1,77 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+/// Known answers for one input of a day.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// An answers file, keyed by year, day and input file name: the checked-in one for the
+/// examples, or the one in the input store for a user's real inputs.
+///
+/// ```toml
+/// [2024.6.input]
+/// part1 = "4758"
+/// part2 = "1670"
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, BTreeMap<String, Expected>>>);
+
+impl Answers {
+    /// Location of `answers.toml` at the root of the repository.
+    pub fn default_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../answers.toml")
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)?;
+        toml::from_str(&contents).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid answers file {}: {}", path.display(), e),
+            )
+        })
+    }
+
+    /// Like [`Answers::load`], but without any answers when the file does not exist.
+    pub fn load_if_present(path: impl AsRef<Path>) -> io::Result<Self> {
+        match Answers::load(&path) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            result => result,
+        }
+    }
+
+    /// Known answers for every recorded input of a day, ordered by input name.
+    pub fn for_day(&self, year: u16, day: u8) -> impl Iterator<Item = (&str, &Expected)> {
+        self.0
+            .get(&year.to_string())
+            .and_then(|days| days.get(&day.to_string()))
+            .into_iter()
+            .flatten()
+            .map(|(name, expected)| (name.as_str(), expected))
+    }
+
+    pub fn get(&self, year: u16, day: u8, input: &str) -> Option<&Expected> {
+        self.for_day(year, day)
+            .find(|(name, _)| *name == input)
+            .map(|(_, expected)| expected)
+    }
+}
@@ -0,0 +1,80 @@
+//! Exact arithmetic on the decimal digits of unsigned integers, without going through
+//! strings or floating point.
+//!
+//! ```
+//! use aoc_common::digits::Digits;
+//!
+//! assert_eq!(1234u64.digit_count(), 4);
+//! assert_eq!(12u64.concat(345), Some(12345));
+//! assert_eq!(12345u64.deconcat(345), Some(12));
+//! assert_eq!(1234u64.split_digits(), Some((12, 34)));
+//! ```
+
+/// Decimal digit operations, implemented for `u64`, `u128` and `usize`.
+pub trait Digits: Sized + Copy {
+    /// Number of decimal digits, where 0 has one.
+    fn digit_count(self) -> u32;
+
+    /// `10^exponent`, unless that overflows.
+    fn checked_pow10(exponent: u32) -> Option<Self>;
+
+    /// The digits of `self` followed by those of `right`, e.g. 12 and 345 give 12345,
+    /// unless that overflows.
+    fn concat(self, right: Self) -> Option<Self>;
+
+    /// The number whose digits followed by those of `suffix` give `self`, e.g. 12345 and
+    /// 345 give 12, or `None` when `self` does not end in `suffix`.
+    ///
+    /// This undoes [`Digits::concat`], so a number that is all suffix leaves 0.
+    fn deconcat(self, suffix: Self) -> Option<Self>;
+
+    /// The first and second half of the digits, e.g. 1234 gives 12 and 34, or `None` when
+    /// there is an odd number of digits. The second half loses its leading zeros, so 1000
+    /// gives 10 and 0.
+    fn split_digits(self) -> Option<(Self, Self)>;
+}
+
+macro_rules! impl_digits {
+    ($($int:ty),*) => {
+        $(
+            impl Digits for $int {
+                fn digit_count(self) -> u32 {
+                    self.checked_ilog10().map_or(1, |log| log + 1)
+                }
+
+                fn checked_pow10(exponent: u32) -> Option<Self> {
+                    (10 as $int).checked_pow(exponent)
+                }
+
+                fn concat(self, right: Self) -> Option<Self> {
+                    let shift = Self::checked_pow10(right.digit_count());
+                    match shift {
+                        Some(shift) => self.checked_mul(shift)?.checked_add(right),
+                        // only a zero can be shifted past the largest power of ten
+                        None => (self == 0).then_some(right),
+                    }
+                }
+
+                fn deconcat(self, suffix: Self) -> Option<Self> {
+                    match Self::checked_pow10(suffix.digit_count()) {
+                        Some(shift) => (self % shift == suffix).then(|| self / shift),
+                        // the suffix has as many digits as the largest numbers
+                        None => (self == suffix).then_some(0),
+                    }
+                }
+
+                fn split_digits(self) -> Option<(Self, Self)> {
+                    let digits = self.digit_count();
+                    if !digits.is_multiple_of(2) {
+                        return None;
+                    }
+                    // half the digits of a number always fit
+                    let shift = Self::checked_pow10(digits / 2).unwrap();
+                    Some((self / shift, self % shift))
+                }
+            }
+        )*
+    };
+}
+
+impl_digits!(u64, u128, usize);
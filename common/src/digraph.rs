@@ -0,0 +1,256 @@
+//! Directed graphs of ordering constraints, e.g. "page 47 must be printed before page 53".
+//!
+//! Nodes are kept in the order they were added, so everything computed from a graph is
+//! deterministic.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::error::Error;
+use std::fmt;
+use std::hash::Hash;
+
+/// A directed graph without parallel edges, stored as adjacency lists.
+#[derive(Clone, Debug)]
+pub struct Digraph<N> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    successors: Vec<Vec<usize>>,
+    predecessors: Vec<Vec<usize>>,
+    edges: HashSet<(usize, usize)>,
+}
+
+impl<N> Default for Digraph<N> {
+    fn default() -> Self {
+        Digraph {
+            nodes: Vec::new(),
+            indices: HashMap::new(),
+            successors: Vec::new(),
+            predecessors: Vec::new(),
+            edges: HashSet::new(),
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash> Digraph<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `node` if it is not in the graph yet, returning its index.
+    fn add_node(&mut self, node: N) -> usize {
+        if let Some(&index) = self.indices.get(&node) {
+            return index;
+        }
+        let index = self.nodes.len();
+        self.nodes.push(node.clone());
+        self.indices.insert(node, index);
+        self.successors.push(Vec::new());
+        self.predecessors.push(Vec::new());
+        index
+    }
+
+    /// Adds an edge from `from` to `to`, and the nodes themselves if they are new.
+    pub fn add_edge(&mut self, from: N, to: N) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        if self.edges.insert((from, to)) {
+            self.successors[from].push(to);
+            self.predecessors[to].push(from);
+        }
+    }
+
+    pub fn contains_edge(&self, from: &N, to: &N) -> bool {
+        match (self.indices.get(from), self.indices.get(to)) {
+            (Some(&from), Some(&to)) => self.edges.contains(&(from, to)),
+            _ => false,
+        }
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Nodes in the order they were added.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.nodes.iter()
+    }
+
+    /// Edges as `(from, to)`, grouped by `from` in the order the nodes were added.
+    pub fn edges(&self) -> impl Iterator<Item = (&N, &N)> {
+        self.successors
+            .iter()
+            .enumerate()
+            .flat_map(move |(from, successors)| {
+                successors
+                    .iter()
+                    .map(move |&to| (&self.nodes[from], &self.nodes[to]))
+            })
+    }
+
+    /// Nodes with an edge from `node`.
+    pub fn successors(&self, node: &N) -> impl Iterator<Item = &N> {
+        let successors = match self.indices.get(node) {
+            Some(&index) => &self.successors[index][..],
+            None => &[],
+        };
+        successors.iter().map(|&index| &self.nodes[index])
+    }
+
+    /// Orders two nodes by the edge between them: `Less` when `a` has an edge to `b`,
+    /// `Greater` when `b` has one to `a` and `Equal` otherwise.
+    ///
+    /// This is only a consistent ordering, as sorting requires, when every pair of sorted
+    /// nodes has an edge between them. Use [`Digraph::topological_sort`] otherwise.
+    pub fn compare(&self, a: &N, b: &N) -> Ordering {
+        if self.contains_edge(a, b) {
+            Ordering::Less
+        } else if self.contains_edge(b, a) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    /// Whether `nodes` are in an order that no edge between them contradicts.
+    pub fn is_sorted(&self, nodes: &[N]) -> bool {
+        nodes.iter().enumerate().all(|(i, earlier)| {
+            nodes[i + 1..]
+                .iter()
+                .all(|later| !self.contains_edge(later, earlier))
+        })
+    }
+
+    /// Orders `subset` so that every edge between its nodes points forwards, ignoring the
+    /// rest of the graph. Nodes not in the graph have no constraints, and repeated nodes are
+    /// only kept once.
+    ///
+    /// The sort is stable: unconstrained nodes keep their order in `subset`, so an already
+    /// sorted subset comes back unchanged.
+    pub fn topological_sort(&self, subset: &[N]) -> Result<Vec<N>, Cycle<N>> {
+        // positions in `subset` of its nodes that are in the graph, by graph index
+        let mut members: HashMap<usize, usize> = HashMap::new();
+        let mut seen = HashSet::new();
+        let mut distinct = Vec::with_capacity(subset.len());
+        for node in subset {
+            if !seen.insert(node) {
+                continue;
+            }
+            if let Some(&index) = self.indices.get(node) {
+                members.insert(index, distinct.len());
+            }
+            distinct.push(node);
+        }
+
+        let mut in_degrees = vec![0; distinct.len()];
+        for (&index, &position) in &members {
+            in_degrees[position] = self.predecessors[index]
+                .iter()
+                .filter(|predecessor| members.contains_key(predecessor))
+                .count();
+        }
+
+        // Kahn's algorithm, always taking the earliest available node of `subset`
+        let mut available: BinaryHeap<Reverse<usize>> = (0..distinct.len())
+            .filter(|&position| in_degrees[position] == 0)
+            .map(Reverse)
+            .collect();
+        let mut sorted = Vec::with_capacity(distinct.len());
+        while let Some(Reverse(position)) = available.pop() {
+            let node = distinct[position];
+            sorted.push(node.clone());
+            let Some(&index) = self.indices.get(node) else {
+                continue;
+            };
+            for successor in &self.successors[index] {
+                if let Some(&next) = members.get(successor) {
+                    in_degrees[next] -= 1;
+                    if in_degrees[next] == 0 {
+                        available.push(Reverse(next));
+                    }
+                }
+            }
+        }
+
+        if sorted.len() == distinct.len() {
+            return Ok(sorted);
+        }
+        // every node left over has a predecessor that is left over too
+        let left_over: HashMap<usize, usize> = members
+            .into_iter()
+            .filter(|&(_, position)| in_degrees[position] > 0)
+            .collect();
+        Err(self.cycle_among(&left_over))
+    }
+
+    /// Some cycle of the graph, if it has any.
+    pub fn find_cycle(&self) -> Option<Cycle<N>> {
+        self.topological_sort(&self.nodes).err()
+    }
+
+    /// Walks back along edges between the `nodes` until one repeats, giving a cycle.
+    ///
+    /// Every one of `nodes` must have a predecessor among them.
+    fn cycle_among(&self, nodes: &HashMap<usize, usize>) -> Cycle<N> {
+        let mut node = *nodes
+            .iter()
+            .min_by_key(|&(_, position)| position)
+            .expect("a failed sort leaves nodes over")
+            .0;
+        // the step at which each node was walked
+        let mut steps = HashMap::new();
+        let mut walked = Vec::new();
+        loop {
+            if let Some(&start) = steps.get(&node) {
+                // the walk went against the edges, so turn it around
+                let nodes = walked[start..]
+                    .iter()
+                    .rev()
+                    .map(|&index: &usize| self.nodes[index].clone())
+                    .collect();
+                return Cycle { nodes };
+            }
+            steps.insert(node, walked.len());
+            walked.push(node);
+            node = *self.predecessors[node]
+                .iter()
+                .find(|predecessor| nodes.contains_key(predecessor))
+                .expect("left over nodes have a left over predecessor");
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash> FromIterator<(N, N)> for Digraph<N> {
+    fn from_iter<I: IntoIterator<Item = (N, N)>>(edges: I) -> Self {
+        let mut graph = Digraph::new();
+        for (from, to) in edges {
+            graph.add_edge(from, to);
+        }
+        graph
+    }
+}
+
+/// Nodes that each have an edge to the next, and the last one back to the first, so they
+/// cannot be ordered.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cycle<N> {
+    pub nodes: Vec<N>,
+}
+
+impl<N: fmt::Display> fmt::Display for Cycle<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cycle ")?;
+        for node in &self.nodes {
+            write!(f, "{} -> ", node)?;
+        }
+        match self.nodes.first() {
+            Some(first) => write!(f, "{}", first),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<N: fmt::Debug + fmt::Display> Error for Cycle<N> {}
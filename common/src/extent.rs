@@ -0,0 +1,294 @@
+//! Allocating runs of consecutive addresses, e.g. blocks on a disk, out of a fixed space.
+//!
+//! Free space is kept as maximal extents: freeing merges an extent with the free ones it
+//! touches. Free extents are indexed by address and by length, so a first fit, or a best
+//! fit anywhere in the space, takes logarithmic time. A best fit below a limit also has
+//! to skip the fitting extents that start too late, one by one.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// A run of `len` consecutive addresses starting at `start`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Extent {
+    pub start: usize,
+    pub len: usize,
+}
+
+impl Extent {
+    pub fn new(start: usize, len: usize) -> Self {
+        Extent { start, len }
+    }
+
+    /// The first address after the extent.
+    pub fn end(&self) -> usize {
+        self.start + self.len
+    }
+
+    pub fn contains(&self, address: usize) -> bool {
+        (self.start..self.end()).contains(&address)
+    }
+}
+
+impl fmt::Display for Extent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end())
+    }
+}
+
+/// Where an allocation is placed among the free extents.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Policy {
+    /// At the start of the lowest-addressed free extent that is large enough
+    FirstFit,
+    /// At the start of the smallest free extent that is large enough, the lowest-addressed
+    /// one of those on a tie
+    BestFit,
+    /// In the lowest free addresses, split over as many free extents as that takes
+    LowestAddress,
+}
+
+/// Free and allocated space within `0..capacity`.
+#[derive(Clone, Debug)]
+pub struct ExtentAllocator {
+    capacity: usize,
+    /// Length of every free extent by its start
+    by_start: BTreeMap<usize, usize>,
+    /// Every free extent as `(len, start)`, for best fits
+    by_len: BTreeSet<(usize, usize)>,
+    /// Length of the free extent starting at every address, for first fits
+    longest: MaxTree,
+    free_len: usize,
+}
+
+impl ExtentAllocator {
+    /// An allocator with all of `0..capacity` free.
+    pub fn new(capacity: usize) -> Self {
+        let mut allocator = ExtentAllocator {
+            capacity,
+            by_start: BTreeMap::new(),
+            by_len: BTreeSet::new(),
+            longest: MaxTree::new(capacity),
+            free_len: 0,
+        };
+        allocator.insert_free(Extent::new(0, capacity));
+        allocator
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Total length of the free space.
+    pub fn free_len(&self) -> usize {
+        self.free_len
+    }
+
+    /// Free extents in address order.
+    pub fn free_extents(&self) -> impl Iterator<Item = Extent> + '_ {
+        self.by_start
+            .iter()
+            .map(|(&start, &len)| Extent::new(start, len))
+    }
+
+    /// The free extent containing `address`, if it is free.
+    pub fn free_extent_at(&self, address: usize) -> Option<Extent> {
+        self.by_start
+            .range(..=address)
+            .next_back()
+            .map(|(&start, &len)| Extent::new(start, len))
+            .filter(|extent| extent.contains(address))
+    }
+
+    /// Allocates `len` addresses anywhere, returning the extents they make up in address
+    /// order, or `None` when there is no room.
+    pub fn allocate(&mut self, len: usize, policy: Policy) -> Option<Vec<Extent>> {
+        self.allocate_below(len, policy, self.capacity)
+    }
+
+    /// Allocates `len` addresses below `limit`, returning the extents they make up in
+    /// address order, or `None` when there is no room. Only [`Policy::LowestAddress`] makes
+    /// more than one extent.
+    pub fn allocate_below(
+        &mut self,
+        len: usize,
+        policy: Policy,
+        limit: usize,
+    ) -> Option<Vec<Extent>> {
+        if len == 0 {
+            return Some(Vec::new());
+        }
+        let limit = limit.min(self.capacity);
+        // the last address a fitting free extent can start at
+        let last_start = limit.checked_sub(len)?;
+
+        let start = match policy {
+            Policy::FirstFit => self.longest.leftmost_at_least(len, last_start)?,
+            Policy::BestFit => {
+                // below the capacity the first fitting extent always starts early enough
+                self.by_len
+                    .range((len, 0)..)
+                    .find(|&&(_, start)| start <= last_start)?
+                    .1
+            }
+            Policy::LowestAddress => return self.allocate_lowest(len, limit),
+        };
+        let extent = Extent::new(start, len);
+        self.claim(extent);
+        Some(vec![extent])
+    }
+
+    /// Takes the lowest free addresses below `limit`, if there are `len` of them.
+    fn allocate_lowest(&mut self, len: usize, limit: usize) -> Option<Vec<Extent>> {
+        let mut extents = Vec::new();
+        let mut needed = len;
+        for (&start, &free) in self.by_start.range(..limit) {
+            let taken = free.min(limit - start).min(needed);
+            extents.push(Extent::new(start, taken));
+            needed -= taken;
+            if needed == 0 {
+                break;
+            }
+        }
+        if needed > 0 {
+            return None;
+        }
+        for &extent in &extents {
+            self.claim(extent);
+        }
+        Some(extents)
+    }
+
+    /// Allocates exactly `extent`.
+    ///
+    /// # Panics
+    /// When any of `extent` is not free.
+    pub fn claim(&mut self, extent: Extent) {
+        if extent.len == 0 {
+            return;
+        }
+        let free = self
+            .free_extent_at(extent.start)
+            .filter(|free| free.end() >= extent.end())
+            .unwrap_or_else(|| panic!("Cannot claim {}, it is not all free", extent));
+
+        self.remove_free(free);
+        if free.start < extent.start {
+            self.insert_free(Extent::new(free.start, extent.start - free.start));
+        }
+        if extent.end() < free.end() {
+            self.insert_free(Extent::new(extent.end(), free.end() - extent.end()));
+        }
+    }
+
+    /// Frees `extent`, coalescing it with the free extents right before and after it.
+    ///
+    /// # Panics
+    /// When any of `extent` is already free or lies outside the allocator.
+    pub fn free(&mut self, extent: Extent) {
+        if extent.len == 0 {
+            return;
+        }
+        assert!(
+            extent.end() <= self.capacity,
+            "Cannot free {}, the capacity is {}",
+            extent,
+            self.capacity
+        );
+        let overlaps = self
+            .by_start
+            .range(..extent.end())
+            .next_back()
+            .is_some_and(|(&start, &len)| start + len > extent.start);
+        assert!(
+            !overlaps,
+            "Cannot free {}, part of it is already free",
+            extent
+        );
+
+        let mut merged = extent;
+        if let Some(before) = extent
+            .start
+            .checked_sub(1)
+            .and_then(|address| self.free_extent_at(address))
+        {
+            self.remove_free(before);
+            merged = Extent::new(before.start, merged.end() - before.start);
+        }
+        if let Some(after) = self.free_extent_at(extent.end()) {
+            self.remove_free(after);
+            merged.len += after.len;
+        }
+        self.insert_free(merged);
+    }
+
+    fn insert_free(&mut self, extent: Extent) {
+        if extent.len == 0 {
+            return;
+        }
+        self.by_start.insert(extent.start, extent.len);
+        self.by_len.insert((extent.len, extent.start));
+        self.longest.set(extent.start, extent.len);
+        self.free_len += extent.len;
+    }
+
+    fn remove_free(&mut self, extent: Extent) {
+        self.by_start.remove(&extent.start);
+        self.by_len.remove(&(extent.len, extent.start));
+        self.longest.set(extent.start, 0);
+        self.free_len -= extent.len;
+    }
+}
+
+/// A segment tree over `0..size` keeping the maximum of every range of values.
+#[derive(Clone, Debug)]
+struct MaxTree {
+    /// Number of leaves, a power of two
+    leaves: usize,
+    /// Node `i` has children `2i` and `2i + 1`, the leaves start at `leaves`
+    nodes: Vec<usize>,
+}
+
+impl MaxTree {
+    fn new(size: usize) -> Self {
+        let leaves = size.max(1).next_power_of_two();
+        MaxTree {
+            leaves,
+            nodes: vec![0; 2 * leaves],
+        }
+    }
+
+    fn set(&mut self, index: usize, value: usize) {
+        let mut node = self.leaves + index;
+        self.nodes[node] = value;
+        while node > 1 {
+            node /= 2;
+            self.nodes[node] = self.nodes[2 * node].max(self.nodes[2 * node + 1]);
+        }
+    }
+
+    /// The lowest index up to `last` holding at least `value`.
+    fn leftmost_at_least(&self, value: usize, last: usize) -> Option<usize> {
+        self.search(1, 0, self.leaves, value, last)
+    }
+
+    /// Searches the node covering `low..high`.
+    fn search(
+        &self,
+        node: usize,
+        low: usize,
+        high: usize,
+        value: usize,
+        last: usize,
+    ) -> Option<usize> {
+        if low > last || self.nodes[node] < value {
+            return None;
+        }
+        if high - low == 1 {
+            return Some(low);
+        }
+        let middle = (low + high) / 2;
+        self.search(2 * node, low, middle, value, last)
+            .or_else(|| self.search(2 * node + 1, middle, high, value, last))
+    }
+}
@@ -0,0 +1,207 @@
+//! A dense, rectangular 2D grid indexed by [`Pos`], with `x` the column and `y` the row.
+
+use std::fmt;
+use std::iter;
+use std::ops::{Index, IndexMut};
+
+use crate::geometry::{Dir4, Dir8, Pos, Vec2};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a `width` x `height` grid filled with `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Builds a grid from rows of equal length.
+    ///
+    /// # Panics
+    /// When the rows differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            assert_eq!(row.len(), width, "Row {} has a different width", y);
+            cells.extend(row);
+        }
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one row per line of `text`, converting every character with `f`.
+    pub fn parse(text: &str, mut f: impl FnMut(char) -> T) -> Self {
+        let rows = text
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| line.chars().map(&mut f).collect())
+            .collect();
+        Self::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[pos.y * self.width + pos.x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.y * self.width + pos.x])
+        } else {
+            None
+        }
+    }
+
+    /// The in-bounds position `offset` away from `pos`, e.g. one step in a direction.
+    pub fn step(&self, pos: Pos, offset: impl Into<Vec2>) -> Option<Pos> {
+        pos.checked_add(offset.into())
+            .filter(|&next| self.contains(next))
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Pos::new(x, y)))
+    }
+
+    /// All cells with their position, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// Position of the first cell, in row-major order, matching `predicate`.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(pos, _)| pos)
+    }
+
+    /// In-bounds positions one step from `pos` in each of `directions`.
+    pub fn neighbours<'a, D, I>(&'a self, pos: Pos, directions: I) -> impl Iterator<Item = Pos> + 'a
+    where
+        D: Into<Vec2>,
+        I: IntoIterator<Item = D>,
+        I::IntoIter: 'a,
+    {
+        directions
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// In-bounds orthogonal neighbours of `pos`, clockwise starting from up.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbours(pos, Dir4::ALL)
+    }
+
+    /// In-bounds orthogonal and diagonal neighbours of `pos`, clockwise starting from up.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbours(pos, Dir8::ALL)
+    }
+
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(self.height)
+    }
+
+    /// Positions visited walking from `start` in steps of `step` until leaving the grid,
+    /// starting with `start` itself. Nothing when `start` is outside the grid.
+    pub fn ray(&self, start: Pos, step: impl Into<Vec2>) -> impl Iterator<Item = Pos> + '_ {
+        let step = step.into();
+        iter::successors(self.contains(start).then_some(start), move |&pos| {
+            self.step(pos, step)
+        })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Draws the grid one line per row, converting every cell with `f`.
+    pub fn render(&self, mut f: impl FnMut(Pos, &T) -> char) -> String {
+        let mut result = String::with_capacity((self.width + 1) * self.height);
+        for (pos, value) in self.iter() {
+            result.push(f(pos, value));
+            if pos.x + 1 == self.width {
+                result.push('\n');
+            }
+        }
+        result
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        assert!(self.contains(pos), "{} is out of bounds", pos);
+        &self.cells[pos.y * self.width + pos.x]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        assert!(self.contains(pos), "{} is out of bounds", pos);
+        &mut self.cells[pos.y * self.width + pos.x]
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(|_, &c| c))
+    }
+}
@@ -0,0 +1,327 @@
+//! Raster images of puzzle grids, written as PPM or PNG, e.g. by `aoc image`.
+//!
+//! Both formats are encoded here rather than through an image library. PNGs are stored
+//! uncompressed, which keeps the encoder small at the cost of larger files.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::geometry::Pos;
+use crate::grid::Grid;
+
+/// An 8-bit colour.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GREY: Rgb = Rgb(128, 128, 128);
+    pub const RED: Rgb = Rgb(220, 40, 40);
+    pub const GREEN: Rgb = Rgb(40, 200, 70);
+    pub const BLUE: Rgb = Rgb(50, 110, 230);
+    pub const YELLOW: Rgb = Rgb(240, 210, 50);
+
+    /// Converts a hue in turns (`0.0..1.0`) and a saturation and value in `0.0..=1.0`.
+    pub fn from_hsv(hue: f64, saturation: f64, value: f64) -> Self {
+        let hue = hue.rem_euclid(1.0) * 6.0;
+        let chroma = value * saturation;
+        let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+        let (r, g, b) = match hue as u8 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let m = value - chroma;
+        let channel = |c: f64| ((c + m) * 255.0).round() as u8;
+        Rgb(channel(r), channel(g), channel(b))
+    }
+
+    /// Blends towards `other`, from `self` at `t = 0.0` to `other` at `t = 1.0`.
+    pub fn mix(self, other: Rgb, t: f64) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Rgb(
+            channel(self.0, other.0),
+            channel(self.1, other.1),
+            channel(self.2, other.2),
+        )
+    }
+}
+
+/// A fixed list of colours to map indices to, e.g. regions or heights.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Palette {
+    colours: Vec<Rgb>,
+}
+
+impl Palette {
+    pub fn new(colours: Vec<Rgb>) -> Self {
+        Palette { colours }
+    }
+
+    /// `n` colours of different hues, for telling categories apart.
+    ///
+    /// Hues are spread by the golden ratio, so consecutive indices are far apart however
+    /// many colours there are.
+    pub fn categorical(n: usize) -> Self {
+        const GOLDEN_RATIO_CONJUGATE: f64 = 0.618_033_988_749_895;
+        let colours = (0..n)
+            .map(|i| {
+                let value = if i % 2 == 0 { 0.95 } else { 0.75 };
+                Rgb::from_hsv(i as f64 * GOLDEN_RATIO_CONJUGATE, 0.65, value)
+            })
+            .collect();
+        Palette::new(colours)
+    }
+
+    /// `n` colours shading evenly from `from` to `to`, for ordered values.
+    pub fn gradient(from: Rgb, to: Rgb, n: usize) -> Self {
+        let colours = (0..n)
+            .map(|i| from.mix(to, i as f64 / (n.max(2) - 1) as f64))
+            .collect();
+        Palette::new(colours)
+    }
+
+    pub fn len(&self) -> usize {
+        self.colours.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.colours.is_empty()
+    }
+
+    /// The colour of `index`, wrapping around when there are fewer colours.
+    ///
+    /// # Panics
+    /// When the palette is empty.
+    pub fn colour(&self, index: usize) -> Rgb {
+        self.colours[index % self.colours.len()]
+    }
+}
+
+/// An image with one pixel per cell.
+pub type Image = Grid<Rgb>;
+
+impl<T> Grid<T> {
+    /// Draws the grid one pixel per cell, colouring every cell with `f`.
+    pub fn to_image(&self, mut f: impl FnMut(Pos, &T) -> Rgb) -> Image {
+        let mut image = Grid::new(self.width(), self.height(), Rgb::BLACK);
+        for (pos, value) in self.iter() {
+            image[pos] = f(pos, value);
+        }
+        image
+    }
+}
+
+impl Grid<Rgb> {
+    /// Enlarges the image, drawing every pixel as a `factor` x `factor` square.
+    pub fn scale(&self, factor: usize) -> Image {
+        let mut image = Grid::new(self.width() * factor, self.height() * factor, Rgb::BLACK);
+        for (pos, &colour) in self.iter() {
+            for dy in 0..factor {
+                for dx in 0..factor {
+                    image[Pos::new(pos.x * factor + dx, pos.y * factor + dy)] = colour;
+                }
+            }
+        }
+        image
+    }
+
+    /// Encodes the image as a binary PPM (P6).
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut bytes = format!("P6\n{} {}\n255\n", self.width(), self.height()).into_bytes();
+        for &Rgb(r, g, b) in self.values() {
+            bytes.extend([r, g, b]);
+        }
+        bytes
+    }
+
+    /// Encodes the image as a truecolour PNG.
+    pub fn to_png(&self) -> Vec<u8> {
+        // every row starts with its filter type, none
+        let mut raw = Vec::with_capacity((self.width() * 3 + 1) * self.height());
+        for row in self.rows() {
+            raw.push(0);
+            for &Rgb(r, g, b) in row {
+                raw.extend([r, g, b]);
+            }
+        }
+
+        let mut header = Vec::with_capacity(13);
+        header.extend((self.width() as u32).to_be_bytes());
+        header.extend((self.height() as u32).to_be_bytes());
+        // 8 bits per channel, truecolour, default compression, filtering and no interlace
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut bytes = b"\x89PNG\r\n\x1a\n".to_vec();
+        write_chunk(&mut bytes, b"IHDR", &header);
+        write_chunk(&mut bytes, b"IDAT", &zlib_stored(&raw));
+        write_chunk(&mut bytes, b"IEND", &[]);
+        bytes
+    }
+
+    /// Writes the image to `path`, as a PNG or PPM depending on its extension.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let bytes = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("png") => self.to_png(),
+            Some("ppm") => self.to_ppm(),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("{} is neither a .png nor a .ppm file", path.display()),
+                ))
+            }
+        };
+        fs::write(path, bytes)
+    }
+}
+
+fn write_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend((data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend(kind);
+    out.extend(data);
+    let crc = crc32(&out[start..]);
+    out.extend(crc.to_be_bytes());
+}
+
+/// Wraps `data` in a zlib stream of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const MAX_BLOCK: usize = u16::MAX as usize;
+
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(MAX_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        // a stream needs at least one, final, block
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        out.push(last as u8);
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(block);
+    }
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MOD_ADLER: u32 = 65521;
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % MOD_ADLER;
+        b = (b + a) % MOD_ADLER;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reads back a stream from `zlib_stored`, checking the framing of every block and the
+    /// checksum, and returns the block lengths and the data.
+    fn read_stored(stream: &[u8]) -> (Vec<usize>, Vec<u8>) {
+        assert_eq!(&stream[..2], [0x78, 0x01]);
+        // the header is a multiple of 31
+        assert_eq!(u16::from_be_bytes([stream[0], stream[1]]) % 31, 0);
+
+        let (mut lengths, mut data) = (Vec::new(), Vec::new());
+        let mut rest = &stream[2..];
+        loop {
+            let last = rest[0];
+            let len = u16::from_le_bytes([rest[1], rest[2]]);
+            assert_eq!(u16::from_le_bytes([rest[3], rest[4]]), !len);
+            let len = len as usize;
+            lengths.push(len);
+            data.extend(&rest[5..5 + len]);
+            rest = &rest[5 + len..];
+            match last {
+                0 => continue,
+                1 => break,
+                _ => panic!("not a stored block: {:#04x}", last),
+            }
+        }
+        assert_eq!(rest, adler32(&data).to_be_bytes());
+        (lengths, data)
+    }
+
+    #[test]
+    fn crc32_known_vectors() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(
+            crc32(b"The quick brown fox jumps over the lazy dog"),
+            0x414f_a339
+        );
+        // the CRC every PNG ends with
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+    }
+
+    #[test]
+    fn adler32_known_vectors() {
+        assert_eq!(adler32(b""), 1);
+        assert_eq!(adler32(b"a"), 0x0062_0062);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+        // long enough for both sums to wrap around the modulus, as computed by zlib
+        assert_eq!(adler32(&[0xff; 6000]), 0xa497_59ea);
+    }
+
+    #[test]
+    fn stored_blocks_split_at_65535_bytes() {
+        assert_eq!(read_stored(&zlib_stored(&[])), (vec![0], vec![]));
+
+        let data: Vec<u8> = (0..65535u32).map(|i| (i % 251) as u8).collect();
+        assert_eq!(read_stored(&zlib_stored(&data)), (vec![65535], data));
+
+        let data: Vec<u8> = (0..2 * 65535 + 10u32).map(|i| (i % 251) as u8).collect();
+        assert_eq!(
+            read_stored(&zlib_stored(&data)),
+            (vec![65535, 65535, 10], data)
+        );
+    }
+
+    #[test]
+    fn png_chunks() {
+        let mut image = Grid::new(2, 1, Rgb::BLACK);
+        image[Pos::new(1, 0)] = Rgb(1, 2, 3);
+        let png = image.to_png();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+
+        let mut chunks = Vec::new();
+        let mut rest = &png[8..];
+        while !rest.is_empty() {
+            let len = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+            let (kind_and_data, crc) = rest[4..12 + len].split_at(4 + len);
+            assert_eq!(crc, crc32(kind_and_data).to_be_bytes());
+            chunks.push((&kind_and_data[..4], &kind_and_data[4..]));
+            rest = &rest[12 + len..];
+        }
+
+        let kinds: Vec<&[u8]> = chunks.iter().map(|&(kind, _)| kind).collect();
+        assert_eq!(kinds, [b"IHDR", b"IDAT", b"IEND"]);
+        assert_eq!(chunks[0].1, [0, 0, 0, 2, 0, 0, 0, 1, 8, 2, 0, 0, 0]);
+        // one row, starting with its filter type
+        let (_, raw) = read_stored(chunks[1].1);
+        assert_eq!(raw, [0, 0, 0, 0, 1, 2, 3]);
+    }
+}
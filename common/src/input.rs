@@ -0,0 +1,138 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use clap::{Args, Parser};
+
+use crate::logging::{self, Verbosity};
+use crate::{parse_input, Solution};
+
+/// Command line flags selecting which puzzle input to read.
+#[derive(Args, Clone, Debug, Default)]
+pub struct InputArgs {
+    /// Read the puzzle input from this file, or `-` for stdin
+    #[arg(long, value_name = "PATH", conflicts_with = "example")]
+    pub input: Option<PathBuf>,
+
+    /// Use the example input from the puzzle description instead of the real one
+    #[arg(long)]
+    pub example: bool,
+
+    /// Read another input of the day by name, e.g. `custom` for `data/custom`
+    #[arg(long, conflicts_with_all = ["input", "example"])]
+    pub name: Option<String>,
+}
+
+/// Error returned from the `main` of a single-day binary.
+///
+/// Returning an error from `main` prints it with `Debug` and exits with a failure, so this
+/// forwards `Debug` to the error's message, e.g. the file, line and column of a parse error.
+pub struct MainError(Box<dyn Error>);
+
+impl<E: Into<Box<dyn Error>>> From<E> for MainError {
+    fn from(error: E) -> Self {
+        MainError(error.into())
+    }
+}
+
+impl fmt::Debug for MainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    input: InputArgs,
+    #[command(flatten)]
+    verbosity: Verbosity,
+}
+
+/// A resolved puzzle input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputArgs {
+    /// Parses the flags of a single-day binary from the process arguments, setting up
+    /// logging from `-v`/`-q` and `RUST_LOG` on the way.
+    pub fn from_env() -> Self {
+        let cli = Cli::parse();
+        logging::init(cli.verbosity);
+        cli.input
+    }
+
+    /// Picks the input to read, falling back to `real` when no flag was given.
+    pub fn resolve(&self, real: impl AsRef<Path>, example: impl AsRef<Path>) -> InputSource {
+        if let Some(name) = &self.name {
+            return InputSource::File(named_path(real, example, name));
+        }
+        match &self.input {
+            Some(path) if path.as_os_str() == "-" => InputSource::Stdin,
+            Some(path) => InputSource::File(path.clone()),
+            None if self.example => InputSource::File(example.as_ref().to_path_buf()),
+            None => InputSource::File(real.as_ref().to_path_buf()),
+        }
+    }
+
+    /// Resolves and reads the selected input.
+    pub fn read(&self, real: impl AsRef<Path>, example: impl AsRef<Path>) -> io::Result<String> {
+        self.resolve(real, example).read()
+    }
+
+    /// Resolves, reads and parses the selected input with `S`.
+    ///
+    /// Parse errors name the file, line and column of the offending text.
+    pub fn load<S: Solution>(
+        &self,
+        real: impl AsRef<Path>,
+        example: impl AsRef<Path>,
+    ) -> Result<S::Input, Box<dyn Error>> {
+        let source = self.resolve(real, example);
+        let input = source.read()?;
+        parse_input::<S>(&input).map_err(|e| e.in_file(source.path()).into())
+    }
+}
+
+/// Path of the input called `name`: next to the real input if it exists there, and
+/// otherwise next to the example.
+pub fn named_path(real: impl AsRef<Path>, example: impl AsRef<Path>, name: &str) -> PathBuf {
+    let next_to_real = real.as_ref().with_file_name(name);
+    let next_to_example = example.as_ref().with_file_name(name);
+    if next_to_real.exists() || !next_to_example.exists() {
+        next_to_real
+    } else {
+        next_to_example
+    }
+}
+
+impl InputSource {
+    /// Path to report in messages, `<stdin>` when reading from stdin.
+    pub fn path(&self) -> &Path {
+        match self {
+            InputSource::Stdin => Path::new("<stdin>"),
+            InputSource::File(path) => path,
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            InputSource::File(path) => fs::read_to_string(path).map_err(|e| {
+                io::Error::new(
+                    e.kind(),
+                    format!("Unable to read {}: {}", path.display(), e),
+                )
+            }),
+        }
+    }
+}
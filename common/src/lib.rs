@@ -0,0 +1,29 @@
+//! Shared building blocks for the Advent of Code solvers.
+
+pub mod alloc;
+pub mod answers;
+pub mod digits;
+pub mod digraph;
+pub mod extent;
+pub mod geometry;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod logging;
+pub mod oracle;
+pub mod parse;
+pub mod random;
+pub mod search;
+pub mod simulation;
+mod solution;
+pub mod store;
+pub mod testing;
+
+pub use answers::Answers;
+pub use geometry::{Dir4, Dir8, Pos, Vec2};
+pub use grid::Grid;
+pub use image::{Image, Palette, Rgb};
+pub use input::{InputArgs, MainError};
+pub use parse::ParseError;
+pub use simulation::{Frame, Simulation};
+pub use solution::{check, draw, parse_input, simulate, solve, time, Answer, Solution, Timings};
@@ -0,0 +1,53 @@
+//! One logging setup for the day binaries and the runner.
+//!
+//! Only warnings and errors are shown by default. `RUST_LOG` takes the usual `env_logger`
+//! directives, e.g. `RUST_LOG=aoc_2024_day_9=debug`, and `-v`/`-q` raise or lower the
+//! level of everything else.
+
+use clap::Args;
+use log::LevelFilter;
+
+const DEFAULT_LEVEL: LevelFilter = LevelFilter::Warn;
+
+/// Command line flags controlling how much is logged.
+#[derive(Args, Clone, Copy, Debug, Default)]
+pub struct Verbosity {
+    /// Log more: -v for info, -vv for debug and -vvv for trace
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    pub verbose: u8,
+
+    /// Log less: -q for errors only, -qq for nothing
+    #[arg(short, long, action = clap::ArgAction::Count, global = true, conflicts_with = "verbose")]
+    pub quiet: u8,
+}
+
+impl Verbosity {
+    /// The level asked for on the command line, if any.
+    pub fn level(&self) -> Option<LevelFilter> {
+        let level = match (self.verbose, self.quiet) {
+            (0, 0) => return None,
+            (0, 1) => LevelFilter::Error,
+            (0, _) => LevelFilter::Off,
+            (1, _) => LevelFilter::Info,
+            (2, _) => LevelFilter::Debug,
+            _ => LevelFilter::Trace,
+        };
+        Some(level)
+    }
+}
+
+/// Sets up logging to stderr from `RUST_LOG` and `verbosity`.
+///
+/// Does nothing when a logger is already installed, so it is safe to call more than once.
+pub fn init(verbosity: Verbosity) {
+    let mut builder = env_logger::Builder::new();
+    builder.filter_level(DEFAULT_LEVEL).format_timestamp(None);
+    if let Ok(filters) = std::env::var("RUST_LOG") {
+        builder.parse_filters(&filters);
+    }
+    // the flags only replace the overall level, per-module directives still apply
+    if let Some(level) = verbosity.level() {
+        builder.filter_level(level);
+    }
+    let _ = builder.try_init();
+}
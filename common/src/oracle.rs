@@ -0,0 +1,60 @@
+//! Checking clever solvers against slow, obviously correct reference implementations,
+//! e.g. by `aoc run --check`.
+
+use std::fmt::{self, Debug};
+
+/// A line of the input on which a solver and its reference implementation disagree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Disagreement {
+    pub part: u8,
+    /// Line number in the input, starting at 1
+    pub line: usize,
+    pub text: String,
+    pub solver: String,
+    pub reference: String,
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "part {}, line {} `{}`: solver gave {}, reference gave {}",
+            self.part, self.line, self.text, self.solver, self.reference
+        )
+    }
+}
+
+/// Outcome of checking a solver against its reference, line by line.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    /// Number of comparisons made
+    pub checked: usize,
+    pub disagreements: Vec<Disagreement>,
+}
+
+impl Report {
+    /// Compares the solver's and the reference's result for one line of the input.
+    pub fn compare<T: PartialEq + Debug>(
+        &mut self,
+        part: u8,
+        line: usize,
+        text: &str,
+        solver: T,
+        reference: T,
+    ) {
+        self.checked += 1;
+        if solver != reference {
+            self.disagreements.push(Disagreement {
+                part,
+                line,
+                text: text.to_string(),
+                solver: format!("{:?}", solver),
+                reference: format!("{:?}", reference),
+            });
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.disagreements.is_empty()
+    }
+}
@@ -0,0 +1,294 @@
+//! Helpers for the input formats that keep coming back: numbers, blank-line separated
+//! blocks, `key: values` lines and character grids.
+
+use std::error::Error;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::Grid;
+
+/// Input that could not be parsed, with the offending text and, once known, where it was found.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub text: String,
+    pub file: Option<PathBuf>,
+    /// 1-based line of `text` in the input
+    pub line: Option<usize>,
+    /// 1-based column of `text`, in characters
+    pub column: Option<usize>,
+    /// Address of `text` while it was still borrowed from the input, see [`ParseError::locate`]
+    origin: usize,
+}
+
+impl ParseError {
+    /// Creates an error for `text`, which should be a slice of the parsed input so it can be
+    /// located afterwards.
+    pub fn new(message: impl Into<String>, text: &str) -> Self {
+        ParseError {
+            message: message.into(),
+            text: text.to_string(),
+            file: None,
+            line: None,
+            column: None,
+            origin: text.as_ptr() as usize,
+        }
+    }
+
+    /// Fills in the line and column of the offending text if it was sliced from `input`.
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        if self.line.is_none() && (start..=start + input.len()).contains(&self.origin) {
+            let before = &input[..self.origin - start];
+            let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+            self.line = Some(before.matches('\n').count() + 1);
+            self.column = Some(before[line_start..].chars().count() + 1);
+        }
+        self
+    }
+
+    pub fn in_file(mut self, file: impl Into<PathBuf>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        if let (Some(line), Some(column)) = (self.line, self.column) {
+            write!(f, "{}:{}:", line, column)?;
+        }
+        if self.file.is_some() || self.line.is_some() {
+            write!(f, " ")?;
+        }
+        write!(f, "{}: {:?}", self.message, self.text)
+    }
+}
+
+impl fmt::Debug for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParseError")
+            .field("message", &self.message)
+            .field("text", &self.text)
+            .field("file", &self.file)
+            .field("line", &self.line)
+            .field("column", &self.column)
+            .finish()
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses a single value, ignoring surrounding whitespace.
+pub fn number<T>(text: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let text = text.trim();
+    text.parse()
+        .map_err(|e| ParseError::new(format!("Invalid number ({})", e), text))
+}
+
+/// Parses whitespace-separated values, e.g. `3   4` or `125 17`.
+pub fn numbers<T>(text: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    text.split_whitespace().map(number).collect()
+}
+
+/// Parses values separated by `separator`, e.g. `75,47,61` or `47|53`.
+pub fn separated<T>(text: &str, separator: char) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    text.trim().split(separator).map(number).collect()
+}
+
+/// Splits `text` into groups of lines separated by one or more blank lines.
+pub fn blocks(text: &str) -> Vec<&str> {
+    let mut blocks = Vec::new();
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+
+    for line in text.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(start) = start.take() {
+                blocks.push(&text[start..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + line.trim_end().len();
+        }
+        offset += line.len();
+    }
+    if let Some(start) = start {
+        blocks.push(&text[start..end]);
+    }
+
+    blocks
+}
+
+/// Parses a `key<separator> values` line, e.g. `190: 10 19` with `':'`.
+pub fn key_values<K, V>(line: &str, separator: char) -> Result<(K, Vec<V>), ParseError>
+where
+    K: FromStr,
+    K::Err: fmt::Display,
+    V: FromStr,
+    V::Err: fmt::Display,
+{
+    let (key, values) = line
+        .split_once(separator)
+        .ok_or_else(|| ParseError::new(format!("Missing '{}'", separator), line))?;
+    Ok((number(key)?, numbers(values)?))
+}
+
+/// Parses a rectangular grid, converting every character with `cell`.
+///
+/// Fails on characters `cell` rejects and on rows of differing length.
+pub fn grid<T>(text: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Grid<T>, ParseError> {
+    let mut rows: Vec<Vec<T>> = Vec::new();
+    for line in text.lines().filter(|line| !line.is_empty()) {
+        let row = line
+            .char_indices()
+            .map(|(i, c)| {
+                cell(c).ok_or_else(|| {
+                    ParseError::new("Unexpected character", &line[i..i + c.len_utf8()])
+                })
+            })
+            .collect::<Result<Vec<T>, _>>()?;
+        if rows.first().is_some_and(|first| first.len() != row.len()) {
+            return Err(ParseError::new(
+                "Row differs in length from the first row",
+                line,
+            ));
+        }
+        rows.push(row);
+    }
+    Ok(Grid::from_rows(rows))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Pos;
+
+    /// Offset of `slice` within `text`, which it must have been sliced from.
+    fn offset(text: &str, slice: &str) -> usize {
+        slice.as_ptr() as usize - text.as_ptr() as usize
+    }
+
+    #[test]
+    fn blocks_are_slices_of_the_input() {
+        let text = "\n\n47|53\n97|13  \n\n\n  \n75,47\r\n61,53\n\n";
+        let blocks = blocks(text);
+        assert_eq!(blocks, ["47|53\n97|13", "75,47\r\n61,53"]);
+        assert_eq!(offset(text, blocks[0]), 2);
+        assert_eq!(offset(text, blocks[1]), 21);
+
+        assert_eq!(super::blocks("a\nb"), ["a\nb"]);
+        assert!(super::blocks("").is_empty());
+        assert!(super::blocks("\n \n\t\n").is_empty());
+    }
+
+    #[test]
+    fn numbers_and_separated_values() {
+        assert_eq!(number::<u32>(" 42\n"), Ok(42));
+        assert_eq!(numbers::<u64>("3   4\t125"), Ok(vec![3, 4, 125]));
+        assert_eq!(numbers::<u64>(""), Ok(vec![]));
+        assert_eq!(separated::<u32>(" 75,47,61\n", ','), Ok(vec![75, 47, 61]));
+        assert_eq!(separated::<u32>("47|53", '|'), Ok(vec![47, 53]));
+
+        let error = separated::<u32>("75,,61", ',').unwrap_err();
+        assert_eq!(error.text, "");
+        let error = separated::<u8>("75,470", ',').unwrap_err();
+        assert_eq!(error.text, "470");
+        assert!(error.message.starts_with("Invalid number"));
+    }
+
+    #[test]
+    fn key_values_split_once() {
+        assert_eq!(
+            key_values::<u64, u64>("190: 10 19", ':'),
+            Ok((190, vec![10, 19]))
+        );
+        assert_eq!(key_values::<u64, u64>("190:", ':'), Ok((190, vec![])));
+        let error = key_values::<u32, u32>("1=2=3", '=').unwrap_err();
+        assert_eq!(error.text, "2=3");
+        let error = key_values::<u64, u64>("190 10 19", ':').unwrap_err();
+        assert_eq!(error.message, "Missing ':'");
+        assert_eq!(error.text, "190 10 19");
+    }
+
+    #[test]
+    fn grids_are_rectangular() {
+        let digit = |c: char| c.to_digit(10);
+        let heights = grid("012\n345\n\n", digit).unwrap();
+        assert_eq!((heights.width(), heights.height()), (3, 2));
+        assert_eq!(heights[Pos::new(2, 1)], 5);
+
+        let input = "012\n34\n678\n";
+        let error = grid(input, digit).unwrap_err().locate(input);
+        assert_eq!(error.message, "Row differs in length from the first row");
+        assert_eq!(
+            (error.text.as_str(), error.line, error.column),
+            ("34", Some(2), Some(1))
+        );
+
+        let input = "012\n3x5\n";
+        let error = grid(input, digit).unwrap_err().locate(input);
+        assert_eq!(
+            (error.text.as_str(), error.line, error.column),
+            ("x", Some(2), Some(2))
+        );
+    }
+
+    #[test]
+    fn errors_are_located_across_lines() {
+        let input = "1 2\n\n3 4\n5 é x 6\n";
+        let line = input.lines().nth(3).unwrap();
+        let error = numbers::<u32>(line).unwrap_err().locate(input);
+        assert_eq!(error.text, "é");
+        // columns count characters rather than bytes
+        let error = numbers::<u32>(&line[5..]).unwrap_err().locate(input);
+        assert_eq!(
+            (error.text.as_str(), error.line, error.column),
+            ("x", Some(4), Some(5))
+        );
+        assert_eq!(
+            error.in_file("data/input").to_string(),
+            "data/input:4:5: Invalid number (invalid digit found in string): \"x\""
+        );
+
+        // text at the very end of the input is still part of it
+        let error = ParseError::new("Missing update", &input[input.len()..]).locate(input);
+        assert_eq!((error.line, error.column), (Some(5), Some(1)));
+    }
+
+    #[test]
+    fn errors_outside_the_input_stay_unlocated() {
+        let input = "1 2\n3 4\n";
+        let owned = String::from("3 4");
+        let error = ParseError::new("Unexpected line", &owned).locate(input);
+        assert_eq!((error.line, error.column), (None, None));
+        assert_eq!(error.to_string(), "Unexpected line: \"3 4\"");
+        assert_eq!(
+            error.in_file("data/input").to_string(),
+            "data/input: Unexpected line: \"3 4\""
+        );
+
+        // a located error keeps its place when located again in another input
+        let error = ParseError::new("Bad", &input[4..])
+            .locate(input)
+            .locate("other");
+        assert_eq!((error.line, error.column), (Some(2), Some(1)));
+    }
+}
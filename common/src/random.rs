@@ -0,0 +1,64 @@
+//! A small seeded random number generator for generating puzzle inputs.
+//!
+//! The same seed always gives the same sequence on every platform, so a generated input
+//! can be reproduced from its seed alone.
+
+/// SplitMix64, which is fast, has no bad seeds and is plenty random for test inputs.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`.
+    ///
+    /// # Panics
+    /// When `n` is zero.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "Cannot pick a number below zero");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// A number in `low..=high`.
+    ///
+    /// # Panics
+    /// When `low` is greater than `high`.
+    pub fn between(&mut self, low: usize, high: usize) -> usize {
+        assert!(low <= high, "Empty range {}..={}", low, high);
+        low + self.below(high - low + 1)
+    }
+
+    /// `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        // the top 53 bits give a uniform float in 0.0..1.0
+        let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        unit < p
+    }
+
+    /// A random element of `items`.
+    ///
+    /// # Panics
+    /// When `items` is empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// Puts `items` in a random order.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
@@ -0,0 +1,259 @@
+//! Graph searches over any node type, with the graph given as a neighbour function.
+//!
+//! Searches keep track of the visited nodes themselves, so the searched data needs no
+//! bookkeeping of its own. On a [`Grid`](crate::Grid), nodes are usually positions:
+//!
+//! ```
+//! use aoc_common::search::Bfs;
+//! use aoc_common::{Grid, Pos};
+//!
+//! let grid = Grid::parse("aab\nabb\n", |c| c);
+//! let start = Pos::new(0, 0);
+//! let region: Vec<Pos> =
+//!     Bfs::new(start, |&pos| grid.neighbours4(pos).filter(|&next| grid[next] == grid[start]))
+//!         .collect();
+//! assert_eq!(region, [Pos::new(0, 0), Pos::new(1, 0), Pos::new(0, 1)]);
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Breadth-first traversal, yielding every node reachable from the start once, nearest
+/// first.
+pub struct Bfs<N, F> {
+    queue: VecDeque<N>,
+    visited: HashSet<N>,
+    neighbours: F,
+}
+
+impl<N, F, I> Bfs<N, F>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    pub fn new(start: N, neighbours: F) -> Self {
+        Bfs {
+            queue: VecDeque::from([start.clone()]),
+            visited: HashSet::from([start]),
+            neighbours,
+        }
+    }
+
+    /// Nodes yielded so far, and those queued to be yielded next.
+    pub fn visited(&self) -> &HashSet<N> {
+        &self.visited
+    }
+}
+
+impl<N, F, I> Iterator for Bfs<N, F>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    type Item = N;
+
+    fn next(&mut self) -> Option<N> {
+        let node = self.queue.pop_front()?;
+        for next in (self.neighbours)(&node) {
+            if self.visited.insert(next.clone()) {
+                self.queue.push_back(next);
+            }
+        }
+        Some(node)
+    }
+}
+
+/// Depth-first traversal, yielding every node reachable from the start once, following
+/// the last neighbour of every node first.
+pub struct Dfs<N, F> {
+    stack: Vec<N>,
+    visited: HashSet<N>,
+    neighbours: F,
+}
+
+impl<N, F, I> Dfs<N, F>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    pub fn new(start: N, neighbours: F) -> Self {
+        Dfs {
+            stack: vec![start.clone()],
+            visited: HashSet::from([start]),
+            neighbours,
+        }
+    }
+
+    /// Nodes yielded so far, and those waiting on the stack.
+    pub fn visited(&self) -> &HashSet<N> {
+        &self.visited
+    }
+}
+
+impl<N, F, I> Iterator for Dfs<N, F>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    type Item = N;
+
+    fn next(&mut self) -> Option<N> {
+        let node = self.stack.pop()?;
+        for next in (self.neighbours)(&node) {
+            if self.visited.insert(next.clone()) {
+                self.stack.push(next);
+            }
+        }
+        Some(node)
+    }
+}
+
+/// Follows `parents` back from `end` to the node without a parent.
+fn reconstruct<N: Clone + Eq + Hash>(parents: &HashMap<N, N>, end: N) -> Vec<N> {
+    let mut path = vec![end];
+    while let Some(parent) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
+
+/// A path with the fewest steps from `start` to a node satisfying `is_goal`, both ends
+/// included.
+pub fn bfs_path<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents = HashMap::new();
+    let mut visited = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(reconstruct(&parents, node));
+        }
+        for next in neighbours(&node) {
+            if visited.insert(next.clone()) {
+                parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// A cheapest path from `start` to a node satisfying `is_goal`, and its cost.
+///
+/// `neighbours` gives every neighbour with the cost of stepping to it, which must not be
+/// negative.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Ord + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// A cheapest path from `start` to a node satisfying `is_goal`, and its cost, guided by
+/// `heuristic`.
+///
+/// The heuristic estimates the cost from a node to the nearest goal. Paths are only
+/// guaranteed to be cheapest when it never overestimates, e.g. the Manhattan distance on
+/// a grid with steps of cost 1.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Ord + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut costs = HashMap::from([(start.clone(), C::default())]);
+    let mut parents = HashMap::new();
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), C::default(), start))]);
+
+    while let Some(Reverse((_, cost, node))) = queue.pop() {
+        if costs.get(&node).is_some_and(|&best| best < cost) {
+            // a cheaper way to this node was found after queueing it
+            continue;
+        }
+        if is_goal(&node) {
+            return Some((reconstruct(&parents, node), cost));
+        }
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            match costs.entry(next.clone()) {
+                Entry::Occupied(best) if *best.get() <= next_cost => continue,
+                Entry::Occupied(mut best) => {
+                    best.insert(next_cost);
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(next_cost);
+                }
+            }
+            parents.insert(next.clone(), node.clone());
+            queue.push(Reverse((next_cost + heuristic(&next), next_cost, next)));
+        }
+    }
+    None
+}
+
+/// Number of distinct paths from `start` to nodes satisfying `is_goal`, where a path ends
+/// at the first goal it reaches.
+///
+/// The graph must not have cycles reachable from `start`. Counts are memoised per node,
+/// so shared sub-paths are only explored once.
+pub fn count_paths<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> usize
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    fn count<N, I>(
+        node: &N,
+        neighbours: &mut impl FnMut(&N) -> I,
+        is_goal: &mut impl FnMut(&N) -> bool,
+        counts: &mut HashMap<N, usize>,
+    ) -> usize
+    where
+        N: Clone + Eq + Hash,
+        I: IntoIterator<Item = N>,
+    {
+        if is_goal(node) {
+            return 1;
+        }
+        if let Some(&paths) = counts.get(node) {
+            return paths;
+        }
+        let paths = neighbours(node)
+            .into_iter()
+            .map(|next| count(&next, neighbours, is_goal, counts))
+            .sum();
+        counts.insert(node.clone(), paths);
+        paths
+    }
+
+    count(&start, &mut neighbours, &mut is_goal, &mut HashMap::new())
+}
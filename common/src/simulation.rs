@@ -0,0 +1,22 @@
+//! Step-by-step simulations that can be played back frame by frame, e.g. by `aoc animate`.
+
+use crate::geometry::Pos;
+
+/// A snapshot of a simulation to draw.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Frame {
+    /// The picture, one line per row
+    pub text: String,
+    /// Position the viewport should keep in view, e.g. the guard
+    pub focus: Option<Pos>,
+    /// One-line description of the current state
+    pub status: String,
+}
+
+/// A puzzle that can be watched while it is being solved.
+pub trait Simulation {
+    /// Advances the simulation by one step, returning `false` once it has finished.
+    fn step(&mut self) -> bool;
+
+    fn frame(&self) -> Frame;
+}
@@ -0,0 +1,131 @@
+use std::fmt::Display;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::image::Image;
+use crate::oracle::Report;
+use crate::parse::ParseError;
+use crate::simulation::Simulation;
+
+/// A puzzle solver, split into a parsing stage and two solving stages.
+///
+/// Implementors are usually unit structs; all state lives in [`Solution::Input`].
+pub trait Solution {
+    /// Parsed representation of the puzzle input, shared by both parts
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// Parses the puzzle input, failing on malformed input rather than panicking.
+    ///
+    /// Errors should be built from slices of `input` so [`parse_input`] can locate them.
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+
+    /// Step-by-step playback of the puzzle, for days that simulate something.
+    fn simulation(_input: &Self::Input) -> Option<Box<dyn Simulation>> {
+        None
+    }
+
+    /// Picture of the puzzle and its solution, one pixel per cell, for days on a grid.
+    fn image(_input: &Self::Input) -> Option<Image> {
+        None
+    }
+
+    /// Compares the solver with a slow reference implementation, for days that have one.
+    fn check(_input: &Self::Input) -> Option<Report> {
+        None
+    }
+}
+
+/// The answer to a single part, rendered as text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Answer {
+    pub part: u8,
+    pub value: String,
+    /// Time spent solving the part, excluding parsing
+    pub elapsed: Duration,
+}
+
+/// Parses `input` with `S`, filling in the line and column of any error.
+pub fn parse_input<S: Solution>(input: &str) -> Result<S::Input, ParseError> {
+    S::parse(input).map_err(|e| e.locate(input))
+}
+
+/// Parses `input` and sets up the simulation of `S`, if it has one.
+pub fn simulate<S: Solution>(input: &str) -> Result<Option<Box<dyn Simulation>>, ParseError> {
+    Ok(S::simulation(&parse_input::<S>(input)?))
+}
+
+/// Parses `input` and draws the image of `S`, if it has one.
+pub fn draw<S: Solution>(input: &str) -> Result<Option<Image>, ParseError> {
+    Ok(S::image(&parse_input::<S>(input)?))
+}
+
+/// Parses `input` and checks `S` against its reference implementation, if it has one.
+pub fn check<S: Solution>(input: &str) -> Result<Option<Report>, ParseError> {
+    Ok(S::check(&parse_input::<S>(input)?))
+}
+
+/// Parses `input` once and solves the requested part, or both parts when `part` is `None`.
+pub fn solve<S: Solution>(input: &str, part: Option<u8>) -> Result<Vec<Answer>, ParseError> {
+    let parsed = parse_input::<S>(input)?;
+    let mut answers = Vec::new();
+
+    if part.is_none_or(|p| p == 1) {
+        let start = Instant::now();
+        let value = S::part1(&parsed).to_string();
+        answers.push(Answer {
+            part: 1,
+            value,
+            elapsed: start.elapsed(),
+        });
+    }
+    if part.is_none_or(|p| p == 2) {
+        let start = Instant::now();
+        let value = S::part2(&parsed).to_string();
+        answers.push(Answer {
+            part: 2,
+            value,
+            elapsed: start.elapsed(),
+        });
+    }
+
+    Ok(answers)
+}
+
+/// Wall-clock time spent in each stage of a single run.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+/// Parses `input` and solves both parts once, timing every stage separately.
+pub fn time<S: Solution>(input: &str) -> Result<Timings, ParseError> {
+    let start = Instant::now();
+    let parsed = black_box(parse_input::<S>(black_box(input))?);
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    black_box(S::part1(&parsed));
+    let part1 = start.elapsed();
+
+    let start = Instant::now();
+    black_box(S::part2(&parsed));
+    let part2 = start.elapsed();
+
+    Ok(Timings {
+        parse,
+        part1,
+        part2,
+    })
+}
@@ -0,0 +1,96 @@
+//! Puzzle inputs kept outside the repository, in one directory per user, year and day:
+//! `$AOC_INPUT_DIR/<user>/<year>/<day>/<name>`, with `~/.aoc/inputs` as the default root.
+//!
+//! Every account gets a different puzzle input, so real inputs belong in the store
+//! rather than in the day packages. The example inputs from the puzzle descriptions are
+//! the same for everyone and stay in the repository. So do their answers: the answers for
+//! a user's inputs are kept in `$AOC_INPUT_DIR/<user>/answers.toml`, in the same format as
+//! the `answers.toml` of the repository.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// Environment variable holding the root directory of the store.
+pub const DIR_VAR: &str = "AOC_INPUT_DIR";
+/// Root of the store when `AOC_INPUT_DIR` is not set, relative to the home directory.
+pub const DEFAULT_DIR: &str = ".aoc/inputs";
+/// Environment variable selecting the user whose inputs are read, defaulting to `$USER`.
+pub const USER_VAR: &str = "AOC_USER";
+
+/// Name of the real puzzle input of a day within the store.
+pub const REAL_INPUT: &str = "input";
+/// Name of the file with the known answers of a user's inputs.
+pub const ANSWERS: &str = "answers.toml";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputStore {
+    root: PathBuf,
+    user: String,
+}
+
+impl InputStore {
+    pub fn new(root: impl Into<PathBuf>, user: impl Into<String>) -> Self {
+        InputStore {
+            root: root.into(),
+            user: user.into(),
+        }
+    }
+
+    /// The store configured through the environment, in `$AOC_INPUT_DIR` if it is set and
+    /// in [`DEFAULT_DIR`] under the home directory otherwise.
+    pub fn from_env() -> Self {
+        let root = match env::var_os(DIR_VAR).filter(|root| !root.is_empty()) {
+            Some(root) => PathBuf::from(root),
+            None => env::var_os("HOME")
+                .map(PathBuf::from)
+                .unwrap_or_default()
+                .join(DEFAULT_DIR),
+        };
+        let user = env::var(USER_VAR)
+            .or_else(|_| env::var("USER"))
+            .ok()
+            .filter(|user| !user.is_empty())
+            .unwrap_or_else(|| String::from("default"));
+        InputStore::new(root, user)
+    }
+
+    pub fn user(&self) -> &str {
+        &self.user
+    }
+
+    /// The known answers for this user's inputs, see [`crate::Answers`].
+    pub fn answers_path(&self) -> PathBuf {
+        self.root.join(&self.user).join(ANSWERS)
+    }
+
+    pub fn day_dir(&self, year: u16, day: u8) -> PathBuf {
+        self.root
+            .join(&self.user)
+            .join(year.to_string())
+            .join(day.to_string())
+    }
+
+    pub fn path(&self, year: u16, day: u8, name: &str) -> PathBuf {
+        self.day_dir(year, day).join(name)
+    }
+
+    /// Names of the inputs stored for a day, sorted. Empty when there are none.
+    pub fn names(&self, year: u16, day: u8) -> Vec<String> {
+        let Ok(entries) = fs::read_dir(self.day_dir(year, day)) else {
+            return Vec::new();
+        };
+        let mut names: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_file())
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .collect();
+        names.sort();
+        names
+    }
+}
+
+/// The real puzzle input of a day in the store configured through the environment.
+pub fn real_input(year: u16, day: u8) -> PathBuf {
+    InputStore::from_env().path(year, day, REAL_INPUT)
+}
@@ -0,0 +1,333 @@
+//! Harnesses for testing the solvers.
+//!
+//! Every file in a day's input directory is treated as an example, except the real
+//! puzzle inputs (`input`, `real` and `part-N`). Examples are checked against the
+//! answers recorded for them in `answers.toml`.
+//!
+//! Property tests check a solver on random inputs from a seeded generator instead, see
+//! [`check_property`], usually against a simple reference through [`check_reference`].
+//! Failing inputs are shrunk before they are reported, see [`shrink`].
+
+use std::env;
+use std::fmt::{Debug, Display};
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+
+use crate::random::Rng;
+use crate::{solve, Answers, Solution};
+
+/// Environment variable overriding the number of random inputs a property is checked on.
+pub const CASES_VAR: &str = "AOC_PROPERTY_CASES";
+/// Environment variables to check a property on a single generated input only.
+pub const SEED_VAR: &str = "AOC_SEED";
+pub const SIZE_VAR: &str = "AOC_SIZE";
+
+const DEFAULT_CASES: u64 = 100;
+/// Largest size passed to generators; sizes grow from 1 over the cases.
+pub const MAX_SIZE: usize = 20;
+/// Smaller inputs tried at most while shrinking a failing input.
+pub const MAX_SHRINK_ATTEMPTS: usize = 2000;
+
+fn is_real_input(name: &str) -> bool {
+    name == "input" || name == "real" || name.starts_with("part-")
+}
+
+/// Example input files in `dir`, sorted by name.
+pub fn example_files(dir: impl AsRef<Path>) -> Vec<PathBuf> {
+    let dir = dir.as_ref();
+    let entries = fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("Unable to read input directory {}: {}", dir.display(), e));
+
+    let mut files: Vec<PathBuf> = entries
+        .map(|entry| entry.expect("Unable to read directory entry").path())
+        .filter(|path| path.is_file())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| !is_real_input(name))
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+/// Solves `part` for every example in `dir` that has a known answer, panicking on the
+/// first mismatch. Returns the number of examples checked.
+pub fn check_examples<S: Solution>(year: u16, day: u8, dir: impl AsRef<Path>, part: u8) -> usize {
+    let answers = Answers::load(Answers::default_path()).expect("Unable to load answers");
+    let files = example_files(dir);
+    assert!(
+        !files.is_empty(),
+        "No example inputs found for {} day {}",
+        year,
+        day
+    );
+
+    let mut checked = 0;
+    for path in files {
+        let name = path.file_name().unwrap().to_string_lossy();
+        let Some(expected) = answers
+            .get(year, day, &name)
+            .and_then(|expected| expected.part(part))
+        else {
+            continue;
+        };
+
+        let input = fs::read_to_string(&path).unwrap();
+        let answer = solve::<S>(&input, Some(part))
+            .unwrap_or_else(|e| panic!("{}", e.in_file(&path)))
+            .remove(0);
+        assert_eq!(
+            answer.value, expected,
+            "{} day {} part {} on example {}",
+            year, day, part, name
+        );
+        checked += 1;
+    }
+    checked
+}
+
+/// Generates `part1` and `part2` tests checking a day against its example inputs.
+///
+/// Each test fails when no example has an answer for its part, so a day cannot pass
+/// without checking anything.
+///
+/// ```ignore
+/// aoc_common::example_tests!(aoc_2024_day_6::Day6, 2024, 6, "data");
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:path, $year:literal, $day:literal, $dir:literal) => {
+        #[test]
+        fn part1() {
+            let checked = $crate::testing::check_examples::<$solution>(
+                $year,
+                $day,
+                concat!(env!("CARGO_MANIFEST_DIR"), "/", $dir),
+                1,
+            );
+            assert!(
+                checked > 0,
+                "No example of {} day {} has a part 1 answer in answers.toml",
+                $year,
+                $day
+            );
+        }
+
+        #[test]
+        fn part2() {
+            let checked = $crate::testing::check_examples::<$solution>(
+                $year,
+                $day,
+                concat!(env!("CARGO_MANIFEST_DIR"), "/", $dir),
+                2,
+            );
+            assert!(
+                checked > 0,
+                "No example of {} day {} has a part 2 answer in answers.toml",
+                $year,
+                $day
+            );
+        }
+    };
+}
+
+fn env_number<T: std::str::FromStr>(name: &str) -> Option<T> {
+    let value = env::var(name).ok()?;
+    let number = value
+        .parse()
+        .unwrap_or_else(|_| panic!("${} should be a number, not {:?}", name, value));
+    Some(number)
+}
+
+/// Checks `property` on `input`, treating a panic as a failure.
+fn run_property(property: &impl Fn(&str) -> Result<(), String>, input: &str) -> Result<(), String> {
+    match panic::catch_unwind(AssertUnwindSafe(|| property(input))) {
+        Ok(outcome) => outcome,
+        Err(payload) => Err(payload
+            .downcast_ref::<String>()
+            .cloned()
+            .or_else(|| payload.downcast_ref::<&str>().map(|s| s.to_string()))
+            .unwrap_or_else(|| String::from("panicked"))),
+    }
+}
+
+/// Splits `line` into its cells: the items between commas if it has any, else those
+/// between spaces, else its characters. Returns the cells and what joins them again.
+fn cells(line: &str) -> (Vec<&str>, &'static str) {
+    if line.contains(',') {
+        (line.split(',').collect(), ",")
+    } else if line.contains(' ') {
+        (line.split(' ').collect(), " ")
+    } else {
+        let cells = line
+            .char_indices()
+            .map(|(i, c)| &line[i..i + c.len_utf8()])
+            .collect();
+        (cells, "")
+    }
+}
+
+/// Smaller versions of `input`, biggest cuts first: without a run of lines, halving the
+/// length of the runs down to single lines, then without a column of a grid, then with a
+/// single cell of a line left out.
+fn shrink_candidates(input: &str) -> Vec<String> {
+    let lines: Vec<&str> = input.lines().collect();
+    let newline = if input.ends_with('\n') { "\n" } else { "" };
+    let join = |lines: Vec<String>| {
+        if lines.is_empty() {
+            String::new()
+        } else {
+            lines.join("\n") + newline
+        }
+    };
+    let mut candidates = Vec::new();
+
+    let mut run = lines.len().div_ceil(2);
+    while run > 0 {
+        for start in (0..lines.len()).step_by(run) {
+            let kept = lines[..start]
+                .iter()
+                .chain(lines.get(start + run..).unwrap_or_default())
+                .map(|line| line.to_string());
+            candidates.push(join(kept.collect()));
+        }
+        run /= 2;
+    }
+
+    let width = lines.first().map_or(0, |line| line.chars().count());
+    if lines.len() > 1 && width > 1 && lines.iter().all(|line| line.chars().count() == width) {
+        for column in 0..width {
+            let kept = lines.iter().map(|line| {
+                line.chars()
+                    .enumerate()
+                    .filter(|&(i, _)| i != column)
+                    .map(|(_, c)| c)
+                    .collect()
+            });
+            candidates.push(join(kept.collect()));
+        }
+    }
+
+    for (i, line) in lines.iter().enumerate() {
+        let (cells, separator) = cells(line);
+        if cells.len() < 2 {
+            continue;
+        }
+        for cell in 0..cells.len() {
+            let mut shrunk: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+            shrunk[i] = [&cells[..cell], &cells[cell + 1..]]
+                .concat()
+                .join(separator);
+            candidates.push(join(shrunk));
+        }
+    }
+
+    candidates
+}
+
+/// Shrinks a failing `input` by leaving out lines, grid columns and cells of lines for as
+/// long as it keeps failing, and returns the smallest failing input found with its failure.
+///
+/// `failure` gives the failure of an input, or `None` when it passes or is not a valid
+/// input, so that shrinking never ends up at an input the puzzle could not have. At most
+/// [`MAX_SHRINK_ATTEMPTS`] smaller inputs are tried.
+pub fn shrink(
+    mut input: String,
+    mut message: String,
+    failure: impl Fn(&str) -> Option<String>,
+) -> (String, String) {
+    let mut attempts = 0;
+    'shrunk: while attempts < MAX_SHRINK_ATTEMPTS {
+        for candidate in shrink_candidates(&input) {
+            if attempts == MAX_SHRINK_ATTEMPTS {
+                break 'shrunk;
+            }
+            attempts += 1;
+            if let Some(shrunk_message) = failure(&candidate) {
+                (input, message) = (candidate, shrunk_message);
+                continue 'shrunk;
+            }
+        }
+        break;
+    }
+    (input, message)
+}
+
+/// Checks `property` on inputs from `generate`, panicking with a failing input.
+///
+/// `generate` builds an input from a random number generator and a size between 1 and
+/// [`MAX_SIZE`], which it is free to interpret, e.g. as a number of lines. Case `n` uses
+/// seed `n`, so failures are reproducible through `AOC_SEED` and `AOC_SIZE`. A failing
+/// input is [shrunk](shrink) before it is reported, trying only smaller inputs that
+/// `valid` accepts.
+pub fn check_property(
+    name: &str,
+    generate: impl Fn(&mut Rng, usize) -> String,
+    valid: impl Fn(&str) -> bool,
+    property: impl Fn(&str) -> Result<(), String>,
+) {
+    let cases: Vec<(u64, usize)> = match env_number::<u64>(SEED_VAR) {
+        Some(seed) => vec![(seed, env_number(SIZE_VAR).unwrap_or(MAX_SIZE))],
+        None => {
+            let count = env_number(CASES_VAR).unwrap_or(DEFAULT_CASES).max(1);
+            (0..count)
+                .map(|seed| (seed, 1 + (seed as usize * MAX_SIZE / count as usize)))
+                .collect()
+        }
+    };
+
+    for (seed, size) in cases {
+        let input = generate(&mut Rng::new(seed), size);
+        let Err(message) = run_property(&property, &input) else {
+            continue;
+        };
+
+        let (shrunk, message) = shrink(input.clone(), message, |candidate| {
+            valid(candidate)
+                .then(|| run_property(&property, candidate).err())
+                .flatten()
+        });
+        panic!(
+            "Property {} failed: {}\n\
+             Shrunk input:\n{}\n\
+             Generated input (seed {}, size {}):\n{}\n\
+             Rerun it with {}={} {}={}",
+            name, message, shrunk, seed, size, input, SEED_VAR, seed, SIZE_VAR, size
+        );
+    }
+}
+
+/// Checks that `S` gives the same answer to `part` as `reference` on inputs from
+/// `generate`, see [`check_property`]. Inputs are parsed and solved like real ones, through
+/// [`solve`], failing inputs are only shrunk to inputs `S` can parse, and `what` names the
+/// answer in failures.
+pub fn check_reference<S: Solution, T: Display>(
+    name: &str,
+    part: u8,
+    what: &str,
+    generate: impl Fn(&mut Rng, usize) -> String,
+    reference: impl Fn(&str) -> T,
+) {
+    let name = format!("{} part {}", name, part);
+    let valid = |input: &str| S::parse(input).is_ok();
+    check_property(&name, generate, valid, |input| {
+        let answer = solve::<S>(input, Some(part))
+            .map_err(|e| e.to_string())?
+            .remove(0);
+        agree(what, answer.value, reference(input).to_string())
+    });
+}
+
+/// Fails with both values when the solver and the reference disagree.
+pub fn agree<T: PartialEq + Debug>(what: &str, solver: T, reference: T) -> Result<(), String> {
+    if solver == reference {
+        Ok(())
+    } else {
+        Err(format!(
+            "{}: solver gave {:?} but the reference gave {:?}",
+            what, solver, reference
+        ))
+    }
+}
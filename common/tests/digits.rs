@@ -0,0 +1,141 @@
+//! The digit helpers against the same operations on decimal strings, for random numbers
+//! of every length and the edge cases around powers of ten.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use aoc_common::digits::Digits;
+use aoc_common::random::Rng;
+
+const CASES: u64 = 2000;
+
+/// Numbers with a random number of digits, so short ones come up as often as long ones.
+fn random_u64(rng: &mut Rng) -> u64 {
+    let digits = rng.between(1, 20) as u32;
+    match u64::checked_pow10(digits) {
+        Some(limit) => rng.next_u64() % limit,
+        None => rng.next_u64(),
+    }
+}
+
+fn random_u128(rng: &mut Rng) -> u128 {
+    let digits = rng.between(1, 39) as u32;
+    let value = ((rng.next_u64() as u128) << 64) | rng.next_u64() as u128;
+    match u128::checked_pow10(digits) {
+        Some(limit) => value % limit,
+        None => value,
+    }
+}
+
+/// Zero, the largest value and every power of ten with its neighbours.
+fn edge_cases<T: Digits + TryFrom<u128>>(max: T) -> Vec<T> {
+    let mut values = vec![max];
+    let mut power: u128 = 1;
+    loop {
+        for value in [power - 1, power, power + 1] {
+            if let Ok(value) = T::try_from(value) {
+                values.push(value);
+            }
+        }
+        match power.checked_mul(10) {
+            Some(next) => power = next,
+            None => break,
+        }
+    }
+    values
+}
+
+fn parse<T: FromStr>(text: &str) -> T {
+    text.parse().ok().expect("a valid number")
+}
+
+/// Checks every helper on `value`, and the ones taking two numbers on `value` and `other`.
+fn check<T>(value: T, other: T)
+where
+    T: Digits + Display + FromStr + PartialEq + std::fmt::Debug,
+{
+    let text = value.to_string();
+    let other_text = other.to_string();
+
+    assert_eq!(
+        value.digit_count() as usize,
+        text.len(),
+        "digits of {}",
+        value
+    );
+
+    // a leading zero is ignored, like concatenating onto 0 does
+    let expected = format!("{}{}", text, other_text).parse().ok();
+    assert_eq!(value.concat(other), expected, "{} concat {}", value, other);
+
+    // every tail of the digits without leading zeros is a suffix the value ends in
+    for at in 0..text.len() {
+        let (prefix, suffix) = text.split_at(at);
+        if suffix.len() > 1 && suffix.starts_with('0') {
+            continue;
+        }
+        let expected = parse(if prefix.is_empty() { "0" } else { prefix });
+        assert_eq!(
+            value.deconcat(parse(suffix)),
+            Some(expected),
+            "{} deconcat {}",
+            value,
+            suffix
+        );
+    }
+    let ends_with = text.len() >= other_text.len() && text.ends_with(&other_text);
+    let expected = ends_with.then(|| {
+        let prefix = &text[..text.len() - other_text.len()];
+        parse(if prefix.is_empty() { "0" } else { prefix })
+    });
+    assert_eq!(
+        value.deconcat(other),
+        expected,
+        "{} deconcat {}",
+        value,
+        other
+    );
+
+    let expected = text.len().is_multiple_of(2).then(|| {
+        let (left, right) = text.split_at(text.len() / 2);
+        (parse(left), parse(right))
+    });
+    assert_eq!(value.split_digits(), expected, "split of {}", value);
+}
+
+#[test]
+fn powers_of_ten() {
+    assert_eq!(u64::checked_pow10(0), Some(1));
+    assert_eq!(u64::checked_pow10(19), Some(10_000_000_000_000_000_000));
+    assert_eq!(u64::checked_pow10(20), None);
+    assert_eq!(u128::checked_pow10(38), Some(10u128.pow(38)));
+    assert_eq!(u128::checked_pow10(39), None);
+}
+
+#[test]
+fn u64_matches_strings() {
+    let values = edge_cases(u64::MAX);
+    for &value in &values {
+        for &other in &values {
+            check(value, other);
+        }
+    }
+    let mut rng = Rng::new(0);
+    for _ in 0..CASES {
+        check(random_u64(&mut rng), random_u64(&mut rng));
+    }
+}
+
+#[test]
+fn u128_matches_strings() {
+    let values = edge_cases(u128::MAX);
+    for &value in &values {
+        for &other in &values {
+            check(value, other);
+        }
+    }
+    let mut rng = Rng::new(0);
+    for _ in 0..CASES {
+        check(random_u128(&mut rng), random_u128(&mut rng));
+    }
+}
@@ -0,0 +1,85 @@
+//! Ordering by edges, sorting subsets and the cycles reported when they cannot be sorted.
+
+use std::cmp::Ordering;
+
+use aoc_common::digraph::{Cycle, Digraph};
+
+/// Checks that `cycle` really goes round the graph, each node with an edge to the next.
+fn assert_cycle(graph: &Digraph<u32>, cycle: &Cycle<u32>) {
+    assert!(!cycle.nodes.is_empty());
+    for (i, from) in cycle.nodes.iter().enumerate() {
+        let to = &cycle.nodes[(i + 1) % cycle.nodes.len()];
+        assert!(graph.contains_edge(from, to), "no edge in {}", cycle);
+    }
+}
+
+#[test]
+fn compare_follows_the_edges() {
+    let graph: Digraph<u32> = [(1, 2), (2, 3), (1, 3)].into_iter().collect();
+    assert_eq!(graph.compare(&1, &2), Ordering::Less);
+    assert_eq!(graph.compare(&3, &2), Ordering::Greater);
+    assert_eq!(graph.compare(&2, &2), Ordering::Equal);
+    assert_eq!(graph.compare(&1, &4), Ordering::Equal);
+
+    // every pair has an edge, so sorting by the edges works
+    let mut pages = vec![3, 1, 2];
+    pages.sort_by(|a, b| graph.compare(a, b));
+    assert_eq!(pages, [1, 2, 3]);
+    assert!(graph.is_sorted(&pages));
+    assert!(!graph.is_sorted(&[2, 1]));
+}
+
+#[test]
+fn sorts_only_the_subset() {
+    let graph: Digraph<u32> = [(1, 2), (2, 3), (3, 4), (5, 6), (6, 5)]
+        .into_iter()
+        .collect();
+
+    assert_eq!(graph.topological_sort(&[4, 3, 1, 2]), Ok(vec![1, 2, 3, 4]));
+    // 2 only comes before 4 through 3, which is left out
+    assert_eq!(graph.topological_sort(&[4, 2]), Ok(vec![4, 2]));
+    // unknown nodes keep their place and repeats are dropped
+    assert_eq!(graph.topological_sort(&[3, 9, 2, 3]), Ok(vec![9, 2, 3]));
+    // the cycle between 5 and 6 is outside the subset
+    assert_eq!(graph.topological_sort(&[2, 5, 1]), Ok(vec![5, 1, 2]));
+    assert_eq!(graph.topological_sort(&[]), Ok(vec![]));
+}
+
+#[test]
+fn reports_a_cycle() {
+    let graph: Digraph<u32> = [(1, 2), (2, 3), (3, 1), (3, 4), (0, 1)]
+        .into_iter()
+        .collect();
+
+    let cycle = graph.find_cycle().unwrap();
+    assert_cycle(&graph, &cycle);
+    let mut nodes = cycle.nodes.clone();
+    nodes.sort();
+    assert_eq!(nodes, [1, 2, 3]);
+
+    let cycle = graph.topological_sort(&[4, 3, 2, 1, 0]).unwrap_err();
+    assert_cycle(&graph, &cycle);
+    assert_eq!(cycle.nodes.len(), 3);
+
+    // leaving out a node of the cycle breaks it
+    assert_eq!(graph.topological_sort(&[3, 1, 0]), Ok(vec![3, 0, 1]));
+}
+
+#[test]
+fn reports_a_self_loop() {
+    let graph: Digraph<u32> = [(1, 2), (2, 2)].into_iter().collect();
+
+    let cycle = graph.find_cycle().unwrap();
+    assert_eq!(cycle, Cycle { nodes: vec![2] });
+    assert_eq!(cycle.to_string(), "cycle 2 -> 2");
+    assert_eq!(graph.topological_sort(&[2, 1]), Err(cycle));
+    assert_eq!(graph.topological_sort(&[1]), Ok(vec![1]));
+}
+
+#[test]
+fn graphs_without_cycles_have_none() {
+    let graph: Digraph<u32> = [(1, 2), (1, 3), (2, 4), (3, 4)].into_iter().collect();
+    assert_eq!(graph.find_cycle(), None);
+    assert_eq!(graph.node_count(), 4);
+    assert_eq!(graph.edge_count(), 4);
+}
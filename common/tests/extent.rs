@@ -0,0 +1,195 @@
+//! The extent allocator against a block-by-block model of the same space.
+
+use aoc_common::extent::{Extent, ExtentAllocator, Policy};
+use aoc_common::random::Rng;
+
+fn free_extents(allocator: &ExtentAllocator) -> Vec<(usize, usize)> {
+    allocator
+        .free_extents()
+        .map(|extent| (extent.start, extent.len))
+        .collect()
+}
+
+#[test]
+fn allocates_from_the_start_of_free_space() {
+    let mut allocator = ExtentAllocator::new(10);
+    assert_eq!(
+        allocator.allocate(3, Policy::FirstFit),
+        Some(vec![Extent::new(0, 3)])
+    );
+    assert_eq!(
+        allocator.allocate(4, Policy::BestFit),
+        Some(vec![Extent::new(3, 4)])
+    );
+    assert_eq!(allocator.allocate(4, Policy::FirstFit), None);
+    assert_eq!(free_extents(&allocator), [(7, 3)]);
+    assert_eq!(allocator.free_len(), 3);
+}
+
+#[test]
+fn freeing_coalesces_neighbours() {
+    let mut allocator = ExtentAllocator::new(10);
+    allocator.allocate(10, Policy::FirstFit).unwrap();
+    allocator.free(Extent::new(2, 2));
+    allocator.free(Extent::new(6, 2));
+    assert_eq!(free_extents(&allocator), [(2, 2), (6, 2)]);
+
+    allocator.free(Extent::new(4, 2));
+    assert_eq!(free_extents(&allocator), [(2, 6)]);
+    allocator.free(Extent::new(0, 2));
+    allocator.free(Extent::new(8, 2));
+    assert_eq!(free_extents(&allocator), [(0, 10)]);
+}
+
+#[test]
+fn policies_pick_different_extents() {
+    // free: 0..4, 6..8 and 9..12
+    let mut allocator = ExtentAllocator::new(12);
+    allocator.claim(Extent::new(4, 2));
+    allocator.claim(Extent::new(8, 1));
+
+    let mut first_fit = allocator.clone();
+    assert_eq!(
+        first_fit.allocate(2, Policy::FirstFit),
+        Some(vec![Extent::new(0, 2)])
+    );
+    let mut best_fit = allocator.clone();
+    assert_eq!(
+        best_fit.allocate(2, Policy::BestFit),
+        Some(vec![Extent::new(6, 2)])
+    );
+    let mut lowest = allocator.clone();
+    assert_eq!(
+        lowest.allocate(7, Policy::LowestAddress),
+        Some(vec![
+            Extent::new(0, 4),
+            Extent::new(6, 2),
+            Extent::new(9, 1)
+        ])
+    );
+    assert_eq!(free_extents(&lowest), [(10, 2)]);
+}
+
+#[test]
+fn allocations_stay_below_the_limit() {
+    // free: 0..2 and 5..10
+    let mut allocator = ExtentAllocator::new(10);
+    allocator.claim(Extent::new(2, 3));
+
+    assert_eq!(
+        allocator.clone().allocate_below(3, Policy::FirstFit, 7),
+        None
+    );
+    assert_eq!(
+        allocator.clone().allocate_below(3, Policy::FirstFit, 8),
+        Some(vec![Extent::new(5, 3)])
+    );
+    assert_eq!(
+        allocator.clone().allocate_below(3, Policy::BestFit, 7),
+        None
+    );
+    assert_eq!(
+        allocator
+            .clone()
+            .allocate_below(4, Policy::LowestAddress, 7),
+        Some(vec![Extent::new(0, 2), Extent::new(5, 2)])
+    );
+    // a failed allocation leaves everything free
+    assert_eq!(allocator.allocate_below(5, Policy::LowestAddress, 7), None);
+    assert_eq!(free_extents(&allocator), [(0, 2), (5, 5)]);
+}
+
+#[test]
+#[should_panic(expected = "already free")]
+fn freeing_free_space_panics() {
+    let mut allocator = ExtentAllocator::new(10);
+    allocator.claim(Extent::new(0, 5));
+    allocator.free(Extent::new(3, 3));
+}
+
+#[test]
+#[should_panic(expected = "not all free")]
+fn claiming_allocated_space_panics() {
+    let mut allocator = ExtentAllocator::new(10);
+    allocator.claim(Extent::new(2, 5));
+    allocator.claim(Extent::new(6, 2));
+}
+
+/// Maximal runs of free blocks as `(start, len)`.
+fn free_runs(free: &[bool]) -> Vec<(usize, usize)> {
+    let mut runs: Vec<(usize, usize)> = Vec::new();
+    for (address, &is_free) in free.iter().enumerate() {
+        match runs.last_mut() {
+            Some((start, len)) if is_free && *start + *len == address => *len += 1,
+            _ if is_free => runs.push((address, 1)),
+            _ => {}
+        }
+    }
+    runs
+}
+
+/// Where the model would put an allocation, found by scanning every block.
+fn model_allocate(free: &[bool], len: usize, policy: Policy, limit: usize) -> Option<Vec<usize>> {
+    let runs = free_runs(free);
+    let mut fitting = runs
+        .iter()
+        .filter(|&&(start, run)| run >= len && start + len <= limit);
+    let start = match policy {
+        Policy::FirstFit => fitting.next()?.0,
+        Policy::BestFit => fitting.min_by_key(|&&(start, run)| (run, start))?.0,
+        Policy::LowestAddress => {
+            let blocks: Vec<usize> = (0..limit).filter(|&a| free[a]).take(len).collect();
+            return (blocks.len() == len).then_some(blocks);
+        }
+    };
+    Some((start..start + len).collect())
+}
+
+#[test]
+fn matches_block_model() {
+    const CAPACITY: usize = 64;
+    let policies = [Policy::FirstFit, Policy::BestFit, Policy::LowestAddress];
+
+    for seed in 0..200 {
+        let mut rng = Rng::new(seed);
+        let mut allocator = ExtentAllocator::new(CAPACITY);
+        let mut free = [true; CAPACITY];
+        let mut allocated: Vec<Extent> = Vec::new();
+
+        for _ in 0..100 {
+            if !allocated.is_empty() && rng.chance(0.4) {
+                let extent = allocated.swap_remove(rng.below(allocated.len()));
+                allocator.free(extent);
+                free[extent.start..extent.end()].fill(true);
+            } else {
+                let len = rng.between(1, 12);
+                let policy = *rng.choose(&policies);
+                let limit = rng.between(0, CAPACITY);
+                let expected = model_allocate(&free, len, policy, limit);
+                let extents = allocator.allocate_below(len, policy, limit);
+                let blocks = extents.as_ref().map(|extents| {
+                    extents
+                        .iter()
+                        .flat_map(|extent| extent.start..extent.end())
+                        .collect::<Vec<_>>()
+                });
+                assert_eq!(
+                    blocks, expected,
+                    "seed {}: allocating {} below {} with {:?}",
+                    seed, len, limit, policy
+                );
+                for extent in extents.into_iter().flatten() {
+                    free[extent.start..extent.end()].fill(false);
+                    allocated.push(extent);
+                }
+            }
+
+            let expected = free_runs(&free);
+            assert_eq!(free_extents(&allocator), expected, "seed {}", seed);
+            assert_eq!(
+                allocator.free_len(),
+                free.iter().filter(|&&is_free| is_free).count()
+            );
+        }
+    }
+}
@@ -0,0 +1,97 @@
+//! Turning, arrows and offsets of the grid geometry types.
+
+use aoc_common::{Dir4, Dir8, Pos, Vec2};
+
+#[test]
+fn dir4_turns() {
+    assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+    assert_eq!(Dir4::Left.turn_right(), Dir4::Up);
+    assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+    assert_eq!(Dir4::Right.turn_left(), Dir4::Up);
+    assert_eq!(Dir4::Up.reverse(), Dir4::Down);
+    assert_eq!(Dir4::Left.reverse(), Dir4::Right);
+
+    for direction in Dir4::ALL {
+        assert_eq!(direction.turn_right().turn_left(), direction);
+        assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+        assert_eq!(direction.reverse().reverse(), direction);
+        assert_eq!(direction.reverse().offset(), -direction.offset());
+    }
+}
+
+#[test]
+fn dir8_turns() {
+    assert_eq!(Dir8::Up.turn_right(), Dir8::UpRight);
+    assert_eq!(Dir8::UpLeft.turn_right(), Dir8::Up);
+    assert_eq!(Dir8::Up.turn_left(), Dir8::UpLeft);
+    assert_eq!(Dir8::DownRight.turn_left(), Dir8::Right);
+    assert_eq!(Dir8::UpRight.reverse(), Dir8::DownLeft);
+    assert_eq!(Dir8::Left.reverse(), Dir8::Right);
+
+    for direction in Dir8::ALL {
+        assert_eq!(direction.turn_right().turn_left(), direction);
+        assert_eq!(direction.reverse().reverse(), direction);
+        assert_eq!(direction.reverse().offset(), -direction.offset());
+        let quarter = direction.turn_right().turn_right();
+        assert_eq!(quarter.turn_right().turn_right(), direction.reverse());
+        assert_eq!(
+            direction.is_diagonal(),
+            !direction.turn_right().is_diagonal()
+        );
+    }
+}
+
+#[test]
+fn arrows_round_trip() {
+    for direction in Dir4::ALL {
+        assert_eq!(Dir4::from_arrow(direction.arrow()), Some(direction));
+    }
+    for arrow in ['^', '>', 'v', '<'] {
+        assert_eq!(Dir4::from_arrow(arrow).map(Dir4::arrow), Some(arrow));
+    }
+    assert_eq!(Dir4::from_arrow('V'), Some(Dir4::Down));
+    assert_eq!(Dir4::from_arrow('x'), None);
+}
+
+#[test]
+fn dir4_maps_to_the_same_dir8() {
+    assert_eq!(Dir8::from(Dir4::Up), Dir8::Up);
+    assert_eq!(Dir8::from(Dir4::Right), Dir8::Right);
+    assert_eq!(Dir8::from(Dir4::Down), Dir8::Down);
+    assert_eq!(Dir8::from(Dir4::Left), Dir8::Left);
+
+    for direction in Dir4::ALL {
+        let dir8 = Dir8::from(direction);
+        assert_eq!(dir8.offset(), direction.offset());
+        assert!(!dir8.is_diagonal());
+        assert_eq!(
+            Dir8::from(direction.turn_right()),
+            dir8.turn_right().turn_right()
+        );
+    }
+}
+
+#[test]
+fn reduced_offsets() {
+    assert_eq!(Vec2::new(4, -6).reduced(), Vec2::new(2, -3));
+    assert_eq!(Vec2::new(-3, -9).reduced(), Vec2::new(-1, -3));
+    assert_eq!(Vec2::new(0, -5).reduced(), Vec2::new(0, -1));
+    assert_eq!(Vec2::new(7, 0).reduced(), Vec2::new(1, 0));
+    assert_eq!(Vec2::new(3, 5).reduced(), Vec2::new(3, 5));
+    assert_eq!(Vec2::ZERO.reduced(), Vec2::ZERO);
+}
+
+#[test]
+fn positions_stop_at_the_origin() {
+    let origin = Pos::new(0, 0);
+    assert_eq!(origin.checked_add(Vec2::new(-1, 0)), None);
+    assert_eq!(origin.checked_add(Vec2::new(0, -1)), None);
+    assert_eq!(origin.step(Dir8::UpLeft), None);
+    assert_eq!(origin.step(Dir4::Down), Some(Pos::new(0, 1)));
+
+    let pos = Pos::new(2, 3);
+    assert_eq!(pos.checked_add(Vec2::new(-2, -3)), Some(origin));
+    assert_eq!(pos.checked_add(Vec2::new(-3, 1)), None);
+    assert_eq!(pos - origin, Vec2::new(2, 3));
+    assert_eq!(pos.manhattan(origin), 5);
+}
@@ -0,0 +1,38 @@
+//! What the single-day binaries print when `main` fails.
+
+use aoc_common::{parse_input, MainError, ParseError, Solution};
+
+struct Numbers;
+
+impl Solution for Numbers {
+    type Input = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+        aoc_common::parse::numbers(input)
+    }
+
+    fn part1(numbers: &Vec<u32>) -> u32 {
+        numbers.iter().sum()
+    }
+
+    fn part2(numbers: &Vec<u32>) -> u32 {
+        numbers.iter().product()
+    }
+}
+
+#[test]
+fn main_errors_print_their_message() {
+    let error = parse_input::<Numbers>("1 2\n3 x\n")
+        .unwrap_err()
+        .in_file("data/input");
+    let error = MainError::from(error);
+    assert_eq!(
+        format!("{:?}", error),
+        "data/input:2:3: Invalid number (invalid digit found in string): \"x\""
+    );
+
+    let error = MainError::from("Unable to read data/input");
+    assert_eq!(format!("{:?}", error), "Unable to read data/input");
+}
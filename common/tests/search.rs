@@ -0,0 +1,156 @@
+//! Shortest and cheapest paths on a small grid where every cell costs its digit to enter
+//! and `#` cannot be entered.
+
+use aoc_common::search::{astar, bfs_path, dijkstra};
+use aoc_common::{Grid, Pos};
+
+// from the top left, the cheapest way to the bottom right of the digits is down and
+// along the bottom, though the ways over the top or through the middle are as short
+const COSTS: &str = "\
+131#1
+191#1
+111#1
+";
+
+fn grid() -> Grid<char> {
+    Grid::parse(COSTS, |c| c)
+}
+
+fn open_neighbours(grid: &Grid<char>, pos: Pos) -> Vec<Pos> {
+    grid.neighbours4(pos)
+        .filter(|&next| grid[next] != '#')
+        .collect()
+}
+
+fn weighted_neighbours(grid: &Grid<char>, pos: Pos) -> Vec<(Pos, u32)> {
+    open_neighbours(grid, pos)
+        .into_iter()
+        .map(|next| (next, grid[next].to_digit(10).unwrap()))
+        .collect()
+}
+
+/// Checks that `path` leads from `start` to `goal` one orthogonal step at a time.
+fn assert_walks(path: &[Pos], start: Pos, goal: Pos) {
+    assert_eq!(path.first(), Some(&start));
+    assert_eq!(path.last(), Some(&goal));
+    for step in path.windows(2) {
+        assert_eq!(step[0].manhattan(step[1]), 1, "{:?}", path);
+    }
+}
+
+#[test]
+fn bfs_path_takes_the_fewest_steps() {
+    let grid = grid();
+    let (start, goal) = (Pos::new(0, 0), Pos::new(2, 2));
+    let path = bfs_path(
+        start,
+        |&pos| open_neighbours(&grid, pos),
+        |&pos| pos == goal,
+    )
+    .unwrap();
+    assert_eq!(path.len(), 5);
+    assert_walks(&path, start, goal);
+
+    assert_eq!(
+        bfs_path(
+            start,
+            |&pos| open_neighbours(&grid, pos),
+            |&pos| pos == start
+        ),
+        Some(vec![start])
+    );
+}
+
+#[test]
+fn dijkstra_takes_the_cheapest_path() {
+    let grid = grid();
+    let (start, goal) = (Pos::new(0, 0), Pos::new(2, 2));
+    let (path, cost) = dijkstra(
+        start,
+        |&pos| weighted_neighbours(&grid, pos),
+        |&pos| pos == goal,
+    )
+    .unwrap();
+    assert_eq!(cost, 4);
+    assert_eq!(
+        path,
+        [
+            Pos::new(0, 0),
+            Pos::new(0, 1),
+            Pos::new(0, 2),
+            Pos::new(1, 2),
+            Pos::new(2, 2)
+        ]
+    );
+
+    let (path, cost) = dijkstra(
+        start,
+        |&pos| weighted_neighbours(&grid, pos),
+        |&pos| pos == Pos::new(2, 0),
+    )
+    .unwrap();
+    // over the top costs 3 + 1, round the bottom six steps of 1
+    assert_eq!(cost, 4);
+    assert_eq!(path, [Pos::new(0, 0), Pos::new(1, 0), Pos::new(2, 0)]);
+}
+
+#[test]
+fn astar_agrees_with_dijkstra() {
+    let grid = grid();
+    let start = Pos::new(0, 0);
+    for goal in grid.positions().filter(|&pos| pos.x < 3) {
+        let expected = dijkstra(
+            start,
+            |&pos| weighted_neighbours(&grid, pos),
+            |&pos| pos == goal,
+        )
+        .map(|(_, cost)| cost);
+        // every step costs at least 1, so the Manhattan distance never overestimates
+        let found = astar(
+            start,
+            |&pos| weighted_neighbours(&grid, pos),
+            |&pos| pos.manhattan(goal) as u32,
+            |&pos| pos == goal,
+        );
+        let (path, cost) = found.unwrap();
+        assert_eq!(Some(cost), expected, "to {}", goal);
+        assert_walks(&path, start, goal);
+        let path_cost: u32 = path[1..]
+            .iter()
+            .map(|&pos| grid[pos].to_digit(10).unwrap())
+            .sum();
+        assert_eq!(path_cost, cost, "to {}", goal);
+    }
+}
+
+#[test]
+fn unreachable_goals_have_no_path() {
+    let grid = grid();
+    let (start, goal) = (Pos::new(0, 0), Pos::new(4, 1));
+
+    assert_eq!(
+        bfs_path(
+            start,
+            |&pos| open_neighbours(&grid, pos),
+            |&pos| pos == goal
+        ),
+        None
+    );
+    assert_eq!(
+        dijkstra(
+            start,
+            |&pos| weighted_neighbours(&grid, pos),
+            |&pos| pos == goal
+        ),
+        None
+    );
+    assert_eq!(
+        astar(
+            start,
+            |&pos| weighted_neighbours(&grid, pos),
+            |&pos| pos.manhattan(goal) as u32,
+            |&pos| pos == goal,
+        ),
+        None
+    );
+}
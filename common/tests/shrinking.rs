@@ -0,0 +1,62 @@
+//! Shrinking failing property inputs by leaving out lines, grid columns and cells.
+
+use std::panic;
+
+use aoc_common::testing::{check_property, shrink};
+
+/// Fails on inputs with a number of at least 50, like a solver that cannot handle them.
+fn large_number(input: &str) -> Option<String> {
+    input
+        .split([' ', ',', '\n'])
+        .filter_map(|number| number.parse::<u32>().ok())
+        .find(|&number| number >= 50)
+        .map(|number| format!("{} is too large", number))
+}
+
+#[test]
+fn shrinks_to_the_failing_cell() {
+    let input = "1 2 3\n4 75 6\n7 8 9\n10,60,11\n";
+    let message = large_number(input).unwrap();
+    let (shrunk, message) = shrink(input.to_string(), message, large_number);
+    // either large number fails on its own
+    assert!(shrunk == "75\n" || shrunk == "60\n", "{:?}", shrunk);
+    assert_eq!(message, format!("{} is too large", shrunk.trim()));
+}
+
+#[test]
+fn shrinks_grids_by_rows_and_columns() {
+    // fails while the grid still has a `#`, and only rectangular grids are valid
+    let failure = |input: &str| {
+        let width = input.lines().next().map_or(0, str::len);
+        let rectangular = input.lines().all(|line| line.len() == width);
+        (rectangular && input.contains('#')).then(|| String::from("wall"))
+    };
+    let (shrunk, _) = shrink(
+        "....\n..#.\n....\n".to_string(),
+        "wall".to_string(),
+        failure,
+    );
+    assert_eq!(shrunk, "#\n");
+}
+
+#[test]
+fn keeps_inputs_that_do_not_shrink() {
+    let (shrunk, message) = shrink("99".to_string(), "too large".to_string(), |_| None);
+    assert_eq!((shrunk.as_str(), message.as_str()), ("99", "too large"));
+}
+
+#[test]
+fn reports_the_shrunk_input() {
+    let generate = |_: &mut aoc_common::random::Rng, _: usize| String::from("1 2\n3 50 4\n");
+    let valid = |input: &str| !input.is_empty();
+    let property = |input: &str| large_number(input).map_or(Ok(()), Err);
+
+    let payload =
+        panic::catch_unwind(|| check_property("large", generate, valid, property)).unwrap_err();
+    let message = payload.downcast_ref::<String>().unwrap();
+    assert!(
+        message.starts_with("Property large failed: 50 is too large\nShrunk input:\n50\n\n"),
+        "{}",
+        message
+    );
+}
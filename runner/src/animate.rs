@@ -0,0 +1,194 @@
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use aoc_common::{Frame, Simulation};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::{cursor, execute, queue, style, terminal};
+
+/// Frames are drawn at most this often; faster playback takes several steps per frame.
+const MAX_REDRAWS_PER_SECOND: u32 = 60;
+
+const HELP: &str = "space: pause  n: step  arrows/hjkl: scroll  f: follow  +/-: speed  q: quit";
+
+/// Puts the terminal in raw mode on an alternate screen, restoring it when dropped.
+struct Screen;
+
+impl Screen {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        if let Err(e) = execute!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide) {
+            let _ = terminal::disable_raw_mode();
+            return Err(e);
+        }
+        Ok(Screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Playback state of the player.
+struct Player {
+    steps_per_second: u32,
+    paused: bool,
+    finished: bool,
+    /// Keep the frame's focus in the middle of the viewport
+    follow: bool,
+    /// Top-left corner of the viewport within the frame
+    offset: (usize, usize),
+    steps: usize,
+}
+
+impl Player {
+    fn new(steps_per_second: u32) -> Self {
+        Player {
+            steps_per_second: steps_per_second.max(1),
+            paused: false,
+            finished: false,
+            follow: true,
+            offset: (0, 0),
+            steps: 0,
+        }
+    }
+
+    fn step(&mut self, simulation: &mut dyn Simulation) {
+        if self.finished {
+            return;
+        }
+        if simulation.step() {
+            self.steps += 1;
+        } else {
+            self.finished = true;
+        }
+    }
+
+    fn scroll(&mut self, dx: isize, dy: isize) {
+        self.follow = false;
+        self.offset.0 = self.offset.0.saturating_add_signed(dx);
+        self.offset.1 = self.offset.1.saturating_add_signed(dy);
+    }
+
+    /// Handles a key press, returning `false` when the player should quit.
+    fn handle(&mut self, key: KeyEvent, simulation: &mut dyn Simulation) -> bool {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char(' ') => self.paused = !self.paused,
+            KeyCode::Char('n') | KeyCode::Char('.') => {
+                self.paused = true;
+                self.step(simulation);
+            }
+            KeyCode::Char('f') => self.follow = !self.follow,
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                self.steps_per_second = self.steps_per_second.saturating_mul(2)
+            }
+            KeyCode::Char('-') => self.steps_per_second = (self.steps_per_second / 2).max(1),
+            KeyCode::Left | KeyCode::Char('h') => self.scroll(-4, 0),
+            KeyCode::Right | KeyCode::Char('l') => self.scroll(4, 0),
+            KeyCode::Up | KeyCode::Char('k') => self.scroll(0, -2),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll(0, 2),
+            _ => {}
+        }
+        true
+    }
+
+    /// Moves the viewport over the frame, following its focus and staying within bounds.
+    fn place_viewport(&mut self, frame: &Frame, width: usize, height: usize) {
+        let frame_height = frame.text.lines().count();
+        let frame_width = frame
+            .text
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        if let (true, Some(focus)) = (self.follow, frame.focus) {
+            self.offset = (
+                focus.x.saturating_sub(width / 2),
+                focus.y.saturating_sub(height / 2),
+            );
+        }
+        self.offset.0 = self.offset.0.min(frame_width.saturating_sub(width));
+        self.offset.1 = self.offset.1.min(frame_height.saturating_sub(height));
+    }
+
+    fn draw(&mut self, out: &mut impl Write, frame: &Frame) -> io::Result<()> {
+        let (columns, rows) = terminal::size()?;
+        let (width, height) = (columns as usize, (rows as usize).saturating_sub(2));
+        self.place_viewport(frame, width, height);
+
+        let mut lines = frame.text.lines().skip(self.offset.1);
+        for row in 0..height {
+            let line: String = lines
+                .next()
+                .map(|line| line.chars().skip(self.offset.0).take(width).collect())
+                .unwrap_or_default();
+            queue!(
+                out,
+                cursor::MoveTo(0, row as u16),
+                style::Print(line),
+                terminal::Clear(terminal::ClearType::UntilNewLine)
+            )?;
+        }
+
+        let state = if self.finished {
+            "finished"
+        } else if self.paused {
+            "paused"
+        } else {
+            "playing"
+        };
+        let status = format!(
+            "{} | step {} | {} steps/s | {}",
+            frame.status, self.steps, self.steps_per_second, state
+        );
+        for (row, text) in [(height, status.as_str()), (height + 1, HELP)] {
+            let text: String = text.chars().take(width).collect();
+            queue!(
+                out,
+                cursor::MoveTo(0, row as u16),
+                style::Print(text),
+                terminal::Clear(terminal::ClearType::UntilNewLine)
+            )?;
+        }
+        out.flush()
+    }
+}
+
+/// Plays `simulation` in the terminal until the user quits.
+pub fn play(simulation: &mut dyn Simulation, steps_per_second: u32) -> io::Result<()> {
+    let _screen = Screen::enter()?;
+    let mut out = io::stdout();
+    let mut player = Player::new(steps_per_second);
+    let redraw_interval = Duration::from_secs(1) / MAX_REDRAWS_PER_SECOND;
+    // steps due but not yet taken, as fast playback runs several steps per frame
+    let mut budget = 0.0;
+    let mut last_tick = Instant::now();
+
+    player.draw(&mut out, &simulation.frame())?;
+    loop {
+        if event::poll(redraw_interval)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press && !player.handle(key, simulation) {
+                    return Ok(());
+                }
+            }
+        }
+
+        let now = Instant::now();
+        if player.paused || player.finished {
+            budget = 0.0;
+        } else {
+            budget += (now - last_tick).as_secs_f64() * player.steps_per_second as f64;
+            while budget >= 1.0 && !player.finished {
+                player.step(simulation);
+                budget -= 1.0;
+            }
+        }
+        last_tick = now;
+        player.draw(&mut out, &simulation.frame())?;
+    }
+}
@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use aoc_common::{ParseError, Timings};
+
+use crate::registry::Day;
+
+/// Spread of repeated measurements of a single stage.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        Stats {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Per-stage statistics of one benchmarked day.
+pub struct Summary {
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+    pub total: Stats,
+}
+
+/// Solves `day` on `input` `runs` times and summarises the timings of every stage.
+///
+/// # Panics
+/// When `runs` is zero.
+pub fn bench_day(day: &Day, input: &str, runs: usize) -> Result<Summary, ParseError> {
+    assert!(runs > 0, "Need at least one run to benchmark");
+    let timings = (0..runs)
+        .map(|_| (day.time)(input))
+        .collect::<Result<Vec<Timings>, _>>()?;
+    let stage = |f: fn(&Timings) -> Duration| Stats::from_samples(timings.iter().map(f).collect());
+
+    Ok(Summary {
+        parse: stage(|t| t.parse),
+        part1: stage(|t| t.part1),
+        part2: stage(|t| t.part2),
+        total: stage(Timings::total),
+    })
+}
+
+pub fn print_header() {
+    println!(
+        "{:<14} {:<7} {:>12} {:>12} {:>12}",
+        "", "stage", "min", "median", "max"
+    );
+}
+
+pub fn print_summary(day: &Day, summary: &Summary) {
+    let label = format!("{} day {}", day.year, day.day);
+    let stages = [
+        ("parse", &summary.parse),
+        ("part 1", &summary.part1),
+        ("part 2", &summary.part2),
+        ("total", &summary.total),
+    ];
+    for (i, (stage, stats)) in stages.into_iter().enumerate() {
+        println!(
+            "{:<14} {:<7} {:>12} {:>12} {:>12}",
+            if i == 0 { label.as_str() } else { "" },
+            stage,
+            format!("{:.2?}", stats.min),
+            format!("{:.2?}", stats.median),
+            format!("{:.2?}", stats.max),
+        );
+    }
+}
+
+/// Prints the sum of the median timings of every day in `year`.
+pub fn print_year_total(year: u16, summaries: &[Summary]) {
+    let sum = |f: fn(&Summary) -> Duration| summaries.iter().map(f).sum::<Duration>();
+    println!(
+        "{} total (sum of medians): parse {:.2?}, part 1 {:.2?}, part 2 {:.2?}, overall {:.2?}",
+        year,
+        sum(|s| s.parse.median),
+        sum(|s| s.part1.median),
+        sum(|s| s.part2.median),
+        sum(|s| s.total.median),
+    );
+}
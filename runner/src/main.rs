@@ -0,0 +1,522 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use aoc_common::input::InputSource;
+use aoc_common::logging::{self, Verbosity};
+use aoc_common::store::{self, InputStore};
+use aoc_common::{Answers, InputArgs};
+use clap::{Parser, Subcommand};
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+
+mod animate;
+mod bench;
+mod memory;
+mod new;
+mod output;
+mod registry;
+mod verify;
+
+use output::{Format, InputResult};
+use registry::Day;
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: aoc_common::alloc::CountingAllocator = aoc_common::alloc::CountingAllocator;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    #[command(flatten)]
+    verbosity: Verbosity,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day, or every day of a year
+    Run {
+        year: u16,
+        day: Option<u8>,
+        /// Only run the given part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        #[command(flatten)]
+        input: InputArgs,
+        /// Output format of the answers
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+        /// Run against every input of the day and show the answers side by side
+        #[arg(long, conflicts_with_all = ["input", "example", "name"])]
+        all_inputs: bool,
+        /// Also run the slow reference implementation of days that have one, and fail on
+        /// any line where it disagrees with the solver
+        #[arg(long, conflicts_with = "all_inputs")]
+        check: bool,
+        /// Number of days to solve at once, defaults to the number of CPUs
+        #[arg(short, long, value_parser = clap::value_parser!(u64).range(1..))]
+        jobs: Option<u64>,
+    },
+    /// Time the parse and solve stages of a single day, or every day of a year
+    Bench {
+        year: u16,
+        day: Option<u8>,
+        /// Number of times to run every day
+        #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        runs: u64,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Count the allocations and peak heap use of every stage of a single day, or every
+    /// day of a year. Needs the count-allocations feature.
+    Memory {
+        year: u16,
+        day: Option<u8>,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Watch a day's simulation step by step in the terminal
+    Animate {
+        year: u16,
+        day: u8,
+        /// Initial playback speed
+        #[arg(long, default_value_t = 30, value_name = "STEPS_PER_SECOND")]
+        speed: u32,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Export a picture of a day's puzzle and solution
+    Image {
+        year: u16,
+        day: u8,
+        /// Image to write, a .png or .ppm file
+        #[arg(short, long)]
+        output: PathBuf,
+        /// Size in pixels of every cell of the grid
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u64).range(1..=64))]
+        scale: u64,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Check solvers against the known answers in answers.toml and in the input store
+    Verify { year: Option<u16>, day: Option<u8> },
+    /// Create and register the package for a new day
+    New {
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+/// Looks up a single day and reads its selected input.
+fn read_day_input(
+    year: u16,
+    day: u8,
+    input: &InputArgs,
+) -> Result<(&'static Day, InputSource, String), String> {
+    let Some(day) = registry::select(Some(year), Some(day)).pop() else {
+        return Err(format!("No solver registered for {} {}", year, day));
+    };
+    let source = input.resolve(day.input_path(), day.example_path());
+    day.check_input(&source)?;
+    let input = source.read().map_err(|e| e.to_string())?;
+    Ok((day, source, input))
+}
+
+/// Solves `day` on the selected input and renders the answers.
+///
+/// With `check`, the day is also checked against its reference implementation, failing
+/// with the lines they disagree on.
+fn run_day(
+    day: &Day,
+    part: Option<u8>,
+    input: &InputArgs,
+    format: Format,
+    check: bool,
+) -> Result<String, String> {
+    let source = input.resolve(day.input_path(), day.example_path());
+    day.check_input(&source)?;
+    let input = source.read().map_err(|e| e.to_string())?;
+    let answers = (day.solve)(&input, part).map_err(|e| e.in_file(source.path()).to_string())?;
+
+    let name = source
+        .path()
+        .file_name()
+        .unwrap_or_default()
+        .to_string_lossy();
+    let mut out = String::new();
+    for answer in answers {
+        output::write_answer(&mut out, format, day, &name, &answer);
+    }
+
+    if check {
+        let report = (day.check)(&input).map_err(|e| e.in_file(source.path()).to_string())?;
+        if let Some(report) = report {
+            output::write_check(&mut out, format, day, &report)?;
+        }
+    }
+    Ok(out)
+}
+
+/// CPU time used so far by all threads of the process, where the platform reports it.
+fn process_cpu_time() -> Option<Duration> {
+    #[cfg(unix)]
+    {
+        let mut time = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        // SAFETY: `time` is a valid timespec for the call to fill in
+        let result = unsafe { libc::clock_gettime(libc::CLOCK_PROCESS_CPUTIME_ID, &mut time) };
+        (result == 0).then(|| Duration::new(time.tv_sec as u64, time.tv_nsec as u32))
+    }
+    #[cfg(not(unix))]
+    {
+        None
+    }
+}
+
+/// What running a day printed, kept until it is the day's turn: the answers for stdout
+/// and the errors for stderr. The day failed if there are any errors.
+struct DayOutput {
+    out: String,
+    errors: Vec<String>,
+}
+
+impl From<Result<String, String>> for DayOutput {
+    fn from(result: Result<String, String>) -> Self {
+        match result {
+            Ok(out) => DayOutput {
+                out,
+                errors: Vec::new(),
+            },
+            Err(e) => DayOutput {
+                out: String::new(),
+                errors: vec![e],
+            },
+        }
+    }
+}
+
+/// Solves `day` on each of its inputs and renders the answers side by side.
+fn run_all_inputs(day: &Day, part: Option<u8>, format: Format) -> DayOutput {
+    let names = day.input_names();
+    if names.is_empty() {
+        return Err(format!("No inputs found for {} day {}", day.year, day.day)).into();
+    }
+
+    let parts = part.map_or(vec![1, 2], |part| vec![part]);
+    let results: Vec<InputResult> = names
+        .into_iter()
+        .map(|name| {
+            let path = day.named_input_path(&name);
+            let input = fs::read_to_string(&path)
+                .map_err(|e| format!("unable to read {}: {}", path.display(), e));
+            // solve the parts separately, so an input that only fits one part still shows it
+            let answers = parts
+                .iter()
+                .map(|&part| {
+                    let input = input.as_ref().map_err(Clone::clone)?;
+                    let mut answers = (day.solve)(input, Some(part)).map_err(|e| e.to_string())?;
+                    Ok(answers.remove(0))
+                })
+                .collect();
+            InputResult { name, answers }
+        })
+        .collect();
+
+    let mut out = String::new();
+    let errors = output::write_results(&mut out, format, day, &parts, &results);
+    DayOutput { out, errors }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    logging::init(cli.verbosity);
+
+    match cli.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            format,
+            all_inputs,
+            check,
+            jobs,
+        } => {
+            let days = registry::select(Some(year), day);
+            if days.is_empty() {
+                eprintln!("No solver registered for {} {:?}", year, day);
+                return ExitCode::FAILURE;
+            }
+            if days.len() > 1 && input.input.is_some() {
+                eprintln!("--input can only be used when running a single day");
+                return ExitCode::FAILURE;
+            }
+
+            let pool = match ThreadPoolBuilder::new()
+                .num_threads(jobs.unwrap_or(0) as usize)
+                .build()
+            {
+                Ok(pool) => pool,
+                Err(e) => {
+                    eprintln!("Unable to start the thread pool: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            // days are independent, so solve them concurrently and print in registry order
+            let start = Instant::now();
+            let cpu_start = process_cpu_time();
+            let outputs: Vec<DayOutput> = pool.install(|| {
+                days.par_iter()
+                    .map(|day| {
+                        if all_inputs {
+                            run_all_inputs(day, part, format)
+                        } else {
+                            run_day(day, part, &input, format, check).into()
+                        }
+                    })
+                    .collect()
+            });
+            let wall_clock = start.elapsed();
+            let cpu_time = process_cpu_time()
+                .zip(cpu_start)
+                .map(|(end, start)| end.saturating_sub(start));
+
+            let mut failed = false;
+            for output in &outputs {
+                print!("{}", output.out);
+                for e in &output.errors {
+                    eprintln!("{}", e);
+                }
+                failed |= !output.errors.is_empty();
+            }
+
+            if days.len() > 1 {
+                let cpu_time = match cpu_time {
+                    Some(cpu_time) => format!(", {:.2?} CPU time", cpu_time),
+                    None => String::new(),
+                };
+                let summary = format!(
+                    "{} days in {:.2?} wall clock{} ({} threads)",
+                    days.len(),
+                    wall_clock,
+                    cpu_time,
+                    pool.current_num_threads()
+                );
+                match format {
+                    Format::Text => println!("{}", summary),
+                    // keep stdout parseable
+                    Format::Json => eprintln!("{}", summary),
+                }
+            }
+            if failed {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Bench {
+            year,
+            day,
+            runs,
+            input,
+        } => {
+            let days = registry::select(Some(year), day);
+            if days.is_empty() {
+                eprintln!("No solver registered for {} {:?}", year, day);
+                return ExitCode::FAILURE;
+            }
+            if days.len() > 1 && input.input.is_some() {
+                eprintln!("--input can only be used when running a single day");
+                return ExitCode::FAILURE;
+            }
+
+            bench::print_header();
+            let mut summaries = Vec::new();
+            for day in days {
+                let source = input.resolve(day.input_path(), day.example_path());
+                let input = match day
+                    .check_input(&source)
+                    .and_then(|_| source.read().map_err(|e| e.to_string()))
+                {
+                    Ok(input) => input,
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        return ExitCode::FAILURE;
+                    }
+                };
+                let summary = match bench::bench_day(day, &input, runs as usize) {
+                    Ok(summary) => summary,
+                    Err(e) => {
+                        eprintln!("{}", e.in_file(source.path()));
+                        return ExitCode::FAILURE;
+                    }
+                };
+                bench::print_summary(day, &summary);
+                summaries.push(summary);
+            }
+            bench::print_year_total(year, &summaries);
+        }
+        Command::Memory { year, day, input } => {
+            if !aoc_common::alloc::is_counting() {
+                eprintln!("Allocations are not counted in this build, rebuild with --features count-allocations");
+                return ExitCode::FAILURE;
+            }
+            let days = registry::select(Some(year), day);
+            if days.is_empty() {
+                eprintln!("No solver registered for {} {:?}", year, day);
+                return ExitCode::FAILURE;
+            }
+            if days.len() > 1 && input.input.is_some() {
+                eprintln!("--input can only be used when running a single day");
+                return ExitCode::FAILURE;
+            }
+
+            // days run one after the other, as the counters are shared by all threads
+            memory::print_header();
+            for day in days {
+                let source = input.resolve(day.input_path(), day.example_path());
+                let input = match day
+                    .check_input(&source)
+                    .and_then(|_| source.read().map_err(|e| e.to_string()))
+                {
+                    Ok(input) => input,
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        return ExitCode::FAILURE;
+                    }
+                };
+                match (day.profile)(&input) {
+                    Ok(memory) => memory::print_memory_use(day, &memory),
+                    Err(e) => {
+                        eprintln!("{}", e.in_file(source.path()));
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+        }
+        Command::Animate {
+            year,
+            day,
+            speed,
+            input,
+        } => {
+            let (day, source, input) = match read_day_input(year, day, &input) {
+                Ok(found) => found,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let mut simulation = match (day.simulate)(&input) {
+                Ok(Some(simulation)) => simulation,
+                Ok(None) => {
+                    eprintln!("{} day {} has no simulation to animate", day.year, day.day);
+                    return ExitCode::FAILURE;
+                }
+                Err(e) => {
+                    eprintln!("{}", e.in_file(source.path()));
+                    return ExitCode::FAILURE;
+                }
+            };
+            if let Err(e) = animate::play(simulation.as_mut(), speed) {
+                eprintln!("Unable to animate in this terminal: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Image {
+            year,
+            day,
+            output,
+            scale,
+            input,
+        } => {
+            let (day, source, input) = match read_day_input(year, day, &input) {
+                Ok(found) => found,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let scale = scale as usize;
+            let image = match (day.draw)(&input) {
+                Ok(Some(image)) => image,
+                Ok(None) => {
+                    eprintln!("{} day {} has no image to draw", day.year, day.day);
+                    return ExitCode::FAILURE;
+                }
+                Err(e) => {
+                    eprintln!("{}", e.in_file(source.path()));
+                    return ExitCode::FAILURE;
+                }
+            };
+            if let Err(e) = image.scale(scale).save(&output) {
+                eprintln!("Unable to write {}: {}", output.display(), e);
+                return ExitCode::FAILURE;
+            }
+            println!(
+                "Wrote {} ({}x{} pixels)",
+                output.display(),
+                image.width() * scale,
+                image.height() * scale
+            );
+        }
+        Command::Verify { year, day } => {
+            // the examples' answers are checked in, those of the real inputs are per user
+            let answers = Answers::load(Answers::default_path()).and_then(|examples| {
+                let store = Answers::load_if_present(InputStore::from_env().answers_path())?;
+                Ok([examples, store])
+            });
+            let answers = match answers {
+                Ok(answers) => answers,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let mut report = verify::Report::default();
+            for day in registry::select(year, day) {
+                for answers in &answers {
+                    verify::verify_day(day, answers, &mut report);
+                }
+            }
+
+            println!(
+                "{} passed, {} failed, {} skipped",
+                report.passed, report.failed, report.skipped
+            );
+            if !report.is_success() {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::New { year, day } => {
+            if !registry::select(Some(year), Some(day)).is_empty() {
+                eprintln!("{} day {} is already registered", year, day);
+                return ExitCode::FAILURE;
+            }
+            match new::generate(year, day) {
+                Ok(dir) => {
+                    let input = store::real_input(year, day);
+                    println!("Created {}", dir);
+                    println!("Put the puzzle input in {}", input.display());
+                    println!("and the example in {}/data/dummy,", dir);
+                    println!(
+                        "with its answers under [{}.{}.dummy] in answers.toml",
+                        year, day
+                    );
+                }
+                Err(e) => {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,45 @@
+use aoc_common::alloc::{AllocStats, MemoryUse};
+
+use crate::registry::Day;
+
+/// Bytes in binary units, e.g. `1.50 MiB`.
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.2} {}", size, UNITS[unit])
+    }
+}
+
+pub fn print_header() {
+    println!(
+        "{:<14} {:<7} {:>12} {:>12} {:>12}",
+        "", "stage", "allocations", "allocated", "peak"
+    );
+}
+
+pub fn print_memory_use(day: &Day, memory: &MemoryUse) {
+    let label = format!("{} day {}", day.year, day.day);
+    let stages: [(&str, &AllocStats); 3] = [
+        ("parse", &memory.parse),
+        ("part 1", &memory.part1),
+        ("part 2", &memory.part2),
+    ];
+    for (i, (stage, stats)) in stages.into_iter().enumerate() {
+        println!(
+            "{:<14} {:<7} {:>12} {:>12} {:>12}",
+            if i == 0 { label.as_str() } else { "" },
+            stage,
+            stats.allocations,
+            format_bytes(stats.bytes),
+            format_bytes(stats.peak),
+        );
+    }
+}
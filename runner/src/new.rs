@@ -0,0 +1,195 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CARGO_TOML: &str = r#"[package]
+name = "{package}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+aoc_common = { path = "../../common" }
+"#;
+
+const LIB_RS: &str = r#"use aoc_common::{ParseError, Solution};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    /// Puzzle input, one entry per line
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(_lines: &Vec<String>) -> usize {
+        todo!()
+    }
+
+    fn part2(_lines: &Vec<String>) -> usize {
+        todo!()
+    }
+}
+"#;
+
+const MAIN_RS: &str = r#"use {package}::Day{day};
+use aoc_common::{store, InputArgs, MainError, Solution};
+
+fn main() -> Result<(), MainError> {
+    let input =
+        InputArgs::from_env().load::<Day{day}>(store::real_input({year}, {day}), "data/dummy")?;
+
+    println!("Part 1: {}", Day{day}::part1(&input));
+    println!("Part 2: {}", Day{day}::part2(&input));
+
+    Ok(())
+}
+"#;
+
+const EXAMPLES_RS: &str = r#"aoc_common::example_tests!({package}::Day{day}, {year}, {day}, "data");
+"#;
+
+/// Repository root, one level above the runner package.
+fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+fn fill(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{package}", &format!("aoc_{}_day_{}", year, day))
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("Unable to write {}: {}", path.display(), e))
+}
+
+/// Adds the day's entry to `DAYS`, keeping the registry sorted by year and day.
+fn register(registry: &str, year: u16, day: u8, entry: &str) -> Option<String> {
+    let start = registry.find("pub const DAYS: &[Day] = days! {\n")?;
+    let end = start + registry[start..].find("\n};")? + 1;
+
+    let mut offset = start + registry[start..].find('\n')? + 1;
+    while offset < end {
+        let line_end = offset + registry[offset..].find('\n')? + 1;
+        let mut fields = registry[offset..line_end]
+            .split(',')
+            .map(|field| field.trim().parse::<u16>());
+        if let (Some(Ok(y)), Some(Ok(d))) = (fields.next(), fields.next()) {
+            if (y, d) > (year, day as u16) {
+                break;
+            }
+        }
+        offset = line_end;
+    }
+
+    Some(format!(
+        "{}    {}\n{}",
+        &registry[..offset],
+        entry,
+        &registry[offset..]
+    ))
+}
+
+/// Adds `dependency` to the end of the `[dependencies]` table of a manifest, which need
+/// not be the last table.
+fn add_dependency(manifest: &str, dependency: &str) -> Option<String> {
+    let start = manifest.find("\n[dependencies]\n")? + 1;
+    // the table runs until the next one starts, or to the end of the manifest
+    let table_end = manifest[start..]
+        .find("\n[")
+        .map_or(manifest.len(), |i| start + i + 1);
+    let end = start + manifest[start..table_end].trim_end().len();
+    Some(format!(
+        "{}\n{}{}",
+        &manifest[..end],
+        dependency,
+        &manifest[end..]
+    ))
+}
+
+fn edit(path: &Path, f: impl FnOnce(&str) -> Option<String>) -> Result<(), String> {
+    let text = fs::read_to_string(path)
+        .map_err(|e| format!("Unable to read {}: {}", path.display(), e))?;
+    let text =
+        f(&text).ok_or_else(|| format!("Unable to find where to edit {}", path.display()))?;
+    write(path, &text)
+}
+
+/// Creates the package for `year`/`day` and registers it with the workspace and the runner.
+///
+/// Returns the package directory, relative to the repository root.
+pub fn generate(year: u16, day: u8) -> Result<String, String> {
+    let root = root();
+    let dir = format!("{}/day_{}", year, day);
+    let package_dir = root.join(&dir);
+    if package_dir.exists() {
+        return Err(format!("{} already exists", dir));
+    }
+
+    for sub_dir in ["src", "data", "tests"] {
+        fs::create_dir_all(package_dir.join(sub_dir))
+            .map_err(|e| format!("Unable to create {}/{}: {}", dir, sub_dir, e))?;
+    }
+    let files = [
+        ("Cargo.toml", CARGO_TOML),
+        ("src/lib.rs", LIB_RS),
+        ("src/main.rs", MAIN_RS),
+        ("tests/examples.rs", EXAMPLES_RS),
+        ("data/dummy", ""),
+    ];
+    for (name, template) in files {
+        write(&package_dir.join(name), &fill(template, year, day))?;
+    }
+
+    let package = format!("aoc_{}_day_{}", year, day);
+    edit(&root.join("Cargo.toml"), |members| {
+        // append to the end of the members list
+        let end = members.find("\n]")? + 1;
+        Some(format!(
+            "{}    \"{}\",\n{}",
+            &members[..end],
+            dir,
+            &members[end..]
+        ))
+    })?;
+    edit(&root.join("runner/Cargo.toml"), |text| {
+        let dependency = format!("{} = {{ path = \"../{}\" }}", package, dir);
+        add_dependency(text, &dependency)
+    })?;
+    edit(&root.join("runner/src/registry.rs"), |text| {
+        let entry = format!(
+            "{}, {}, \"{}\", \"data/dummy\", {}::Day{};",
+            year, day, dir, package, day
+        );
+        register(text, year, day, &entry)
+    })?;
+
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::add_dependency;
+
+    #[test]
+    fn dependencies_go_into_their_table() {
+        let manifest = "[package]\nname = \"aoc\"\n\n[dependencies]\nrayon = \"1.10\"\n\n\
+                        [target.'cfg(unix)'.dependencies]\nlibc = \"0.2\"\n";
+        assert_eq!(
+            add_dependency(manifest, "day = { path = \"../day\" }").unwrap(),
+            "[package]\nname = \"aoc\"\n\n[dependencies]\nrayon = \"1.10\"\n\
+             day = { path = \"../day\" }\n\n[target.'cfg(unix)'.dependencies]\nlibc = \"0.2\"\n"
+        );
+
+        let manifest = "[package]\nname = \"aoc\"\n\n[dependencies]\nrayon = \"1.10\"\n";
+        assert_eq!(
+            add_dependency(manifest, "day = { path = \"../day\" }").unwrap(),
+            "[package]\nname = \"aoc\"\n\n[dependencies]\nrayon = \"1.10\"\nday = { path = \"../day\" }\n"
+        );
+        assert_eq!(add_dependency("[package]\n", "day = \"1\""), None);
+    }
+}
@@ -0,0 +1,170 @@
+use std::fmt::Write;
+
+use aoc_common::oracle::Report;
+use aoc_common::Answer;
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::registry::Day;
+
+/// How answers are written to stdout.
+///
+/// Answers are rendered into a buffer first, so days solved concurrently can still be
+/// printed in registry order.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// One human-readable line per answer
+    #[default]
+    Text,
+    /// One JSON object per answer, one per line
+    Json,
+}
+
+/// A single answer as emitted by `--format json`.
+#[derive(Serialize)]
+struct Record<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    /// File name of the input, or `<stdin>`
+    input: &'a str,
+    elapsed_ms: f64,
+}
+
+pub fn write_answer(out: &mut String, format: Format, day: &Day, input: &str, answer: &Answer) {
+    match format {
+        Format::Text => writeln!(
+            out,
+            "{} day {} part {}: {}",
+            day.year, day.day, answer.part, answer.value
+        )
+        .unwrap(),
+        Format::Json => {
+            let record = Record {
+                year: day.year,
+                day: day.day,
+                part: answer.part,
+                answer: &answer.value,
+                input,
+                elapsed_ms: answer.elapsed.as_secs_f64() * 1000.0,
+            };
+            let json = serde_json::to_string(&record).expect("Answer records always serialize");
+            writeln!(out, "{}", json).unwrap();
+        }
+    }
+}
+
+/// Answers of a day on one of its inputs, per part, or why a part has none.
+pub struct InputResult {
+    pub name: String,
+    pub answers: Vec<Result<Answer, String>>,
+}
+
+/// Writes the answers for every input of a day, as a table with one row per input in
+/// text format.
+///
+/// Returns the errors to report besides: every failed part in JSON format, and in text
+/// format, where the table shows them already, which inputs failed.
+pub fn write_results(
+    out: &mut String,
+    format: Format,
+    day: &Day,
+    parts: &[u8],
+    results: &[InputResult],
+) -> Vec<String> {
+    let mut errors = Vec::new();
+    if format == Format::Json {
+        for result in results {
+            for answer in &result.answers {
+                match answer {
+                    Ok(answer) => write_answer(out, format, day, &result.name, answer),
+                    Err(e) => errors.push(format!(
+                        "{} day {} [{}]: {}",
+                        day.year, day.day, result.name, e
+                    )),
+                }
+            }
+        }
+        return errors;
+    }
+
+    let failed: Vec<&str> = results
+        .iter()
+        .filter(|result| result.answers.iter().any(Result::is_err))
+        .map(|result| result.name.as_str())
+        .collect();
+    if !failed.is_empty() {
+        errors.push(format!(
+            "{} day {}: no answer on {}",
+            day.year,
+            day.day,
+            failed.join(", ")
+        ));
+    }
+
+    let header: Vec<String> = std::iter::once(format!("{} day {}", day.year, day.day))
+        .chain(parts.iter().map(|part| format!("part {}", part)))
+        .collect();
+    let rows: Vec<Vec<String>> = results
+        .iter()
+        .map(|result| {
+            let cells = result.answers.iter().map(|answer| match answer {
+                Ok(answer) => answer.value.clone(),
+                Err(e) => e.clone(),
+            });
+            std::iter::once(result.name.clone()).chain(cells).collect()
+        })
+        .collect();
+
+    let widths: Vec<usize> = (0..header.len())
+        .map(|column| {
+            std::iter::once(&header)
+                .chain(&rows)
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    for row in std::iter::once(&header).chain(&rows) {
+        let line: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        writeln!(out, "{}", line.join("  ").trim_end()).unwrap();
+    }
+    errors
+}
+
+/// Notes that a day agrees with its reference implementation, or fails with every line
+/// where it does not.
+pub fn write_check(
+    out: &mut String,
+    format: Format,
+    day: &Day,
+    report: &Report,
+) -> Result<(), String> {
+    if !report.is_success() {
+        let mut message = format!(
+            "{} day {} disagrees with its reference on {} of {} checks:",
+            day.year,
+            day.day,
+            report.disagreements.len(),
+            report.checked
+        );
+        for disagreement in &report.disagreements {
+            message += &format!("\n  {}", disagreement);
+        }
+        return Err(message);
+    }
+    if format == Format::Text {
+        writeln!(
+            out,
+            "{} day {} agrees with its reference on all {} checks",
+            day.year, day.day, report.checked
+        )
+        .unwrap();
+    }
+    Ok(())
+}
@@ -0,0 +1,150 @@
+use std::fs;
+use std::path::PathBuf;
+
+use aoc_common::alloc::MemoryUse;
+use aoc_common::input::{self, InputSource};
+use aoc_common::oracle::Report;
+use aoc_common::store::{self, InputStore};
+use aoc_common::{Answer, Image, ParseError, Simulation, Timings};
+
+/// Sets up the simulation of a day from its input, if the day has one.
+type Simulate = fn(&str) -> Result<Option<Box<dyn Simulation>>, ParseError>;
+
+/// Draws the image of a day from its input, if the day has one.
+type Draw = fn(&str) -> Result<Option<Image>, ParseError>;
+
+/// Checks a day against its reference implementation, if the day has one.
+type Check = fn(&str) -> Result<Option<Report>, ParseError>;
+
+/// Solves a day once, counting the memory used by each stage.
+type Profile = fn(&str) -> Result<MemoryUse, ParseError>;
+
+/// A solver that can be dispatched to by the runner.
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    /// Package directory, relative to the repository root
+    pub dir: &'static str,
+    /// Example input from the puzzle description, relative to the package directory
+    pub example: &'static str,
+    pub solve: fn(&str, Option<u8>) -> Result<Vec<Answer>, ParseError>,
+    pub time: fn(&str) -> Result<Timings, ParseError>,
+    pub simulate: Simulate,
+    pub draw: Draw,
+    pub check: Check,
+    pub profile: Profile,
+}
+
+impl Day {
+    pub fn dir_path(&self) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(self.dir)
+    }
+
+    /// The real puzzle input, in the input store.
+    pub fn input_path(&self) -> PathBuf {
+        store::real_input(self.year, self.day)
+    }
+
+    pub fn example_path(&self) -> PathBuf {
+        self.dir_path().join(self.example)
+    }
+
+    /// Path of another input of the day, e.g. `custom` for `data/custom`, preferring the
+    /// input store over the package.
+    pub fn named_input_path(&self, name: &str) -> PathBuf {
+        input::named_path(self.input_path(), self.example_path(), name)
+    }
+
+    /// Names of the inputs in the input store and the package, sorted.
+    pub fn input_names(&self) -> Vec<String> {
+        let mut names = InputStore::from_env().names(self.year, self.day);
+        if let Some(Ok(entries)) = self.example_path().parent().map(fs::read_dir) {
+            names.extend(
+                entries
+                    .filter_map(|entry| entry.ok())
+                    .filter(|entry| entry.path().is_file())
+                    .filter_map(|entry| entry.file_name().into_string().ok()),
+            );
+        }
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    /// Fails with the available inputs when `source` names a file that does not exist.
+    pub fn check_input(&self, source: &InputSource) -> Result<(), String> {
+        let InputSource::File(path) = source else {
+            return Ok(());
+        };
+        if path.is_file() {
+            return Ok(());
+        }
+
+        let mut message = format!(
+            "No input for {} day {} at {}",
+            self.year,
+            self.day,
+            path.display()
+        );
+        message += &format!(
+            " (input store of user {}, see ${} and ${})",
+            InputStore::from_env().user(),
+            store::DIR_VAR,
+            store::USER_VAR
+        );
+        let names = self.input_names();
+        if !names.is_empty() {
+            message += &format!("\nAvailable inputs: {}", names.join(", "));
+        }
+        Err(message)
+    }
+}
+
+macro_rules! days {
+    ($($year:literal, $day:literal, $dir:literal, $example:literal, $solution:path;)*) => {
+        &[$(
+            Day {
+                year: $year,
+                day: $day,
+                dir: $dir,
+                example: $example,
+                solve: aoc_common::solve::<$solution>,
+                time: aoc_common::time::<$solution>,
+                simulate: aoc_common::simulate::<$solution>,
+                draw: aoc_common::draw::<$solution>,
+                check: aoc_common::check::<$solution>,
+                profile: aoc_common::alloc::profile::<$solution>,
+            },
+        )*]
+    };
+}
+
+pub const DAYS: &[Day] = days! {
+    2022, 1, "2022/day_1", "input/test", aoc_2022_day_1::Day1;
+    2022, 2, "2022/day_2", "input/test", aoc_2022_day_2::Day2;
+    2022, 3, "2022/day_3", "input/test", aoc_2022_day_3::Day3;
+    2023, 1, "2023/day-1", "input/dummy", aoc_2023_day_1::Day1;
+    2023, 2, "2023/day-2", "input/dummy", aoc_2023_day_2::Day2;
+    2024, 1, "2024/day_1", "data/dummy", aoc_2024_day_1::Day1;
+    2024, 2, "2024/day_2", "data/dummy", aoc_2024_day_2::Day2;
+    2024, 3, "2024/day_3", "data/dummy", aoc_2024_day_3::Day3;
+    2024, 4, "2024/day_4", "data/dummy", aoc_2024_day_4::Day4;
+    2024, 5, "2024/day_5", "data/dummy", aoc_2024_day_5::Day5;
+    2024, 6, "2024/day_6", "data/dummy", aoc_2024_day_6::Day6;
+    2024, 7, "2024/day_7", "data/dummy", aoc_2024_day_7::Day7;
+    2024, 8, "2024/day_8", "data/dummy", aoc_2024_day_8::Day8;
+    2024, 9, "2024/day_9", "data/dummy", aoc_2024_day_9::Day9;
+    2024, 10, "2024/day_10", "data/dummy", aoc_2024_day_10::Day10;
+    2024, 11, "2024/day_11", "data/dummy", aoc_2024_day_11::Day11;
+    2024, 12, "2024/day_12", "data/dummy", aoc_2024_day_12::Day12;
+};
+
+/// All registered days matching the given year and day, in registry order.
+pub fn select(year: Option<u16>, day: Option<u8>) -> Vec<&'static Day> {
+    DAYS.iter()
+        .filter(|d| year.is_none_or(|y| d.year == y))
+        .filter(|d| day.is_none_or(|n| d.day == n))
+        .collect()
+}
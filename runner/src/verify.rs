@@ -0,0 +1,78 @@
+use std::fs;
+use std::io;
+
+use aoc_common::Answers;
+
+use crate::registry::Day;
+
+/// Outcome of checking a day against the answers file.
+#[derive(Default)]
+pub struct Report {
+    pub passed: usize,
+    pub failed: usize,
+    /// Inputs with known answers that are not there to check
+    pub skipped: usize,
+}
+
+impl Report {
+    pub fn is_success(&self) -> bool {
+        self.failed == 0
+    }
+}
+
+/// Runs `day` on every input recorded for it in `answers` and compares the answers.
+///
+/// Inputs that do not exist are skipped, so the answers of somebody else's store or of a
+/// missing real input do not count as failures.
+pub fn verify_day(day: &Day, answers: &Answers, report: &mut Report) {
+    for (name, expected) in answers.for_day(day.year, day.day) {
+        let label = format!("{} day {} [{}]", day.year, day.day, name);
+        let path = day.named_input_path(name);
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                println!("{}: skipped, {} not found", label, path.display());
+                report.skipped += 1;
+                continue;
+            }
+            Err(e) => {
+                println!("{}: unable to read {}: {}", label, path.display(), e);
+                report.failed += 1;
+                continue;
+            }
+        };
+
+        // only solve the parts that have a known answer
+        let part = match (&expected.part1, &expected.part2) {
+            (Some(_), Some(_)) => None,
+            (Some(_), None) => Some(1),
+            (None, Some(_)) => Some(2),
+            (None, None) => continue,
+        };
+
+        let answers = match (day.solve)(&input, part) {
+            Ok(answers) => answers,
+            Err(e) => {
+                println!("{}: {}", label, e.in_file(&path));
+                report.failed += 1;
+                continue;
+            }
+        };
+
+        for answer in answers {
+            let Some(expected) = expected.part(answer.part) else {
+                continue;
+            };
+            if answer.value == expected {
+                println!("{} part {}: ok", label, answer.part);
+                report.passed += 1;
+            } else {
+                println!(
+                    "{} part {}: MISMATCH, expected {} but got {}",
+                    label, answer.part, expected, answer.value
+                );
+                report.failed += 1;
+            }
+        }
+    }
+}